@@ -0,0 +1,177 @@
+use std::io::{self, Read};
+
+use crate::{
+    utils::error::error_check, Error, FromValue, InternalConnection, Result, ToValue, Value,
+    ValueRef,
+};
+
+/// External file referenced by a BFILE value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BFile {
+    /// Name of the directory object created by `CREATE DIRECTORY`
+    pub directory: String,
+    pub filename: String,
+}
+
+impl BFile {
+    pub fn new<D: Into<String>, F: Into<String>>(directory: D, filename: F) -> Self {
+        Self {
+            directory: directory.into(),
+            filename: filename.into(),
+        }
+    }
+}
+
+impl ToValue for BFile {
+    fn to_value(&self) -> Value {
+        Value::BFile(self.directory.clone(), self.filename.clone())
+    }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::BFile(&self.directory, &self.filename))
+    }
+}
+
+impl FromValue for BFile {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::BFile(directory, filename) => Ok(Self {
+                directory,
+                filename,
+            }),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                std::any::type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+/// Owned BFILE locator handle
+pub(crate) struct BFileLocator(pub(crate) dmdb_sys::dhbfile);
+
+impl BFileLocator {
+    pub(crate) fn alloc(conn: &InternalConnection) -> Result<Self> {
+        let mut bfile: dmdb_sys::dhbfile = std::ptr::null_mut();
+        unsafe {
+            let rt = dmdb_sys::dpi_alloc_bfile(conn.hcon, &mut bfile);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, conn.hcon, msg => Error::Statement(format!("Allocate bfile failed: {}", msg)));
+        }
+
+        Ok(Self(bfile))
+    }
+
+    /// Allocate a BFILE locator which references `filename` in the `directory` object
+    pub(crate) fn construct(
+        conn: &InternalConnection,
+        directory: &str,
+        filename: &str,
+    ) -> Result<Self> {
+        let bfile = Self::alloc(conn)?;
+        let directory = std::ffi::CString::new(directory)
+            .map_err(|e| Error::Parameter(format!("Invalid bfile directory: {e}")))?;
+        let filename = std::ffi::CString::new(filename)
+            .map_err(|e| Error::Parameter(format!("Invalid bfile filename: {e}")))?;
+
+        unsafe {
+            let rt = dmdb_sys::dpi_bfile_construct(
+                bfile.0,
+                directory.as_ptr() as *mut dmdb_sys::udbyte,
+                filename.as_ptr() as *mut dmdb_sys::udbyte,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_BFILE, bfile.0, msg => Error::Parameter(format!("Construct bfile failed: {}", msg)));
+        }
+
+        Ok(bfile)
+    }
+
+    /// Get (directory, filename)
+    pub(crate) fn name(&self) -> Result<(String, String)> {
+        let mut dir_buf = [0u8; 256];
+        let mut dir_len: dmdb_sys::udint4 = 0;
+        let mut file_buf = [0u8; 512];
+        let mut file_len: dmdb_sys::udint4 = 0;
+
+        unsafe {
+            let rt = dmdb_sys::dpi_bfile_get_name(
+                self.0,
+                dir_buf.as_mut_ptr(),
+                dir_buf.len() as dmdb_sys::udint4,
+                &mut dir_len,
+                file_buf.as_mut_ptr(),
+                file_buf.len() as dmdb_sys::udint4,
+                &mut file_len,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_BFILE, self.0, msg => Error::Statement(format!("Get bfile name failed: {}", msg)));
+        }
+
+        let dir = dir_buf.get(..dir_len as usize).unwrap_or(&dir_buf);
+        let file = file_buf.get(..file_len as usize).unwrap_or(&file_buf);
+
+        Ok((
+            String::from_utf8_lossy(dir).to_string(),
+            String::from_utf8_lossy(file).to_string(),
+        ))
+    }
+}
+
+impl Drop for BFileLocator {
+    fn drop(&mut self) {
+        unsafe {
+            dmdb_sys::dpi_free_bfile(self.0);
+        }
+    }
+}
+
+/// Reader of the external file referenced by a BFILE value, see [`Row::get_bfile`](crate::Row::get_bfile)
+pub struct BFileReader<'conn> {
+    bfile: BFileLocator,
+    /// 0-based read position in bytes
+    pos: u64,
+    _conn: &'conn InternalConnection,
+}
+
+impl<'conn> BFileReader<'conn> {
+    pub(crate) fn open(
+        conn: &'conn InternalConnection,
+        directory: &str,
+        filename: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            bfile: BFileLocator::construct(conn, directory, filename)?,
+            pos: 0,
+            _conn: conn,
+        })
+    }
+}
+
+impl Read for BFileReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut data_get: dmdb_sys::udint8 = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_bfile_read(
+                self.bfile.0,
+                self.pos + 1,
+                dmdb_sys::DSQL_C_BINARY as dmdb_sys::sdint2,
+                buf.len() as dmdb_sys::udint8,
+                buf.as_mut_ptr() as dmdb_sys::dpointer,
+                buf.len() as dmdb_sys::udint8,
+                &mut data_get,
+            );
+            if rt == dmdb_sys::DSQL_NO_DATA as dmdb_sys::DPIRETURN {
+                return Ok(0);
+            }
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_BFILE, self.bfile.0, msg => io::Error::other(msg));
+        }
+
+        let n = (data_get as usize).min(buf.len());
+        self.pos += n as u64;
+
+        Ok(n)
+    }
+}
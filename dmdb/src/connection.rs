@@ -1,19 +1,32 @@
+use std::mem::size_of_val;
+
 use crate::{
+    rowid,
     utils::{cstring::CString, error::error_check},
-    Error, Params, Result, Row, Statement, Transaction,
+    Error, Params, Result, Row, RowId, Statement, Transaction,
 };
 
+/// Max session time zone offset in minutes
+const MAX_TIME_ZONE: i16 = 14 * 60;
+
 pub struct Connection {
     server: String,
     user: String,
     password: String,
+    /// Session time zone in minutes, applied on each (re)connect
+    time_zone: Option<i16>,
     conn: Option<InternalConnection>,
 }
 
 macro_rules! require_conn {
     ($self:expr) => {{
         if $self.conn.is_none() {
-            let conn = InternalConnection::connect(&$self.server, &$self.user, &$self.password)?;
+            let conn = InternalConnection::connect(
+                &$self.server,
+                &$self.user,
+                &$self.password,
+                $self.time_zone,
+            )?;
             $self.conn = Some(conn);
         }
 
@@ -44,6 +57,7 @@ impl Connection {
             server: server.into(),
             user: user.into(),
             password: pwd.into(),
+            time_zone: None,
             conn: None,
         };
 
@@ -83,17 +97,49 @@ impl Connection {
         let conn = require_conn!(self);
         drop_conn_on_error!(self, conn.transaction())
     }
+
+    /// Set session time zone as offset minutes from UTC, it is kept across reconnects.
+    ///
+    /// `WITH LOCAL TIME ZONE` values are converted to this time zone.
+    pub fn set_time_zone(&mut self, offset: i16) -> Result<()> {
+        if !(-MAX_TIME_ZONE..=MAX_TIME_ZONE).contains(&offset) {
+            return Err(Error::Parameter(format!(
+                "Time zone offset `{}` out of range, must be within ±14:00",
+                offset
+            )));
+        }
+
+        let conn = require_conn!(self);
+        drop_conn_on_error!(self, conn.set_time_zone(offset))?;
+
+        // Keep the offset only if the server accepted it, otherwise every reconnect fails
+        self.time_zone = Some(offset);
+
+        Ok(())
+    }
+
+    /// Get session time zone as offset minutes from UTC
+    pub fn time_zone(&mut self) -> Result<i16> {
+        let conn = require_conn!(self);
+        drop_conn_on_error!(self, conn.time_zone())
+    }
+
+    /// Build ROWID from node number, partition table id and real row id
+    pub fn build_rowid(&mut self, epno: i32, partno: i64, real_rowid: u64) -> Result<RowId> {
+        let conn = require_conn!(self);
+        drop_conn_on_error!(self, conn.build_rowid(epno, partno, real_rowid))
+    }
 }
 
 pub struct InternalConnection {
     henv: dmdb_sys::dhenv,
-    hcon: dmdb_sys::dhcon,
+    pub(crate) hcon: dmdb_sys::dhcon,
 }
 
 unsafe impl Send for InternalConnection {}
 
 impl InternalConnection {
-    pub fn connect(server: &str, user: &str, pwd: &str) -> Result<Self> {
+    pub fn connect(server: &str, user: &str, pwd: &str, time_zone: Option<i16>) -> Result<Self> {
         let mut henv: dmdb_sys::dhenv = std::ptr::null_mut();
         let mut hcon: dmdb_sys::dhcon = std::ptr::null_mut();
 
@@ -124,6 +170,16 @@ impl InternalConnection {
             );
             error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, hcon, msg => Error::Connection(msg));
 
+            if let Some(offset) = time_zone {
+                let rt = dmdb_sys::dpi_set_con_attr(
+                    hcon,
+                    dmdb_sys::DSQL_ATTR_TIME_ZONE as _,
+                    offset as isize as _,
+                    0,
+                );
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, hcon, msg => Error::Connection(msg));
+            }
+
             let rt = dmdb_sys::dpi_login(
                 hcon,
                 CString::new(server).as_ptr_mut(),
@@ -155,7 +211,12 @@ impl InternalConnection {
             error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, hstmt, msg => Error::Prepare(msg));
         }
 
-        Ok(Statement::new(hstmt, self))
+        let mut stmt = Statement::new(hstmt, self);
+        // Not all statements can be described, the describe error is kept and the values
+        // are bound by their own types then
+        stmt.describe_params();
+
+        Ok(stmt)
     }
 
     pub fn execute<P: Params>(&self, sql: &str, params: P) -> Result<()> {
@@ -211,6 +272,49 @@ impl InternalConnection {
 
         Ok(())
     }
+
+    pub fn set_time_zone(&self, offset: i16) -> Result<()> {
+        unsafe {
+            let rt = dmdb_sys::dpi_set_con_attr(
+                self.hcon,
+                dmdb_sys::DSQL_ATTR_TIME_ZONE as _,
+                offset as isize as _,
+                0,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, self.hcon, msg => Error::Connection(msg));
+        }
+
+        Ok(())
+    }
+
+    pub fn time_zone(&self) -> Result<i16> {
+        // Use a 4 bytes buffer, the attribute may be written as a sdint2 or a sdint4
+        let mut offset: dmdb_sys::sdint4 = 0;
+        let mut len: dmdb_sys::sdint4 = 0;
+
+        unsafe {
+            let rt = dmdb_sys::dpi_get_con_attr(
+                self.hcon,
+                dmdb_sys::DSQL_ATTR_TIME_ZONE as _,
+                &mut offset as *mut _ as dmdb_sys::dpointer,
+                size_of_val(&offset) as _,
+                &mut len,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, self.hcon, msg => Error::Connection(msg));
+        }
+
+        if len as usize == size_of::<i16>() {
+            // The sdint2 is in the first 2 bytes on any endianness
+            let bytes = offset.to_ne_bytes();
+            return Ok(i16::from_ne_bytes([bytes[0], bytes[1]]));
+        }
+        i16::try_from(offset)
+            .map_err(|_| Error::Connection(format!("Time zone offset `{}` out of range", offset)))
+    }
+
+    pub fn build_rowid(&self, epno: i32, partno: i64, real_rowid: u64) -> Result<RowId> {
+        rowid::build_rowid(self, epno, partno, real_rowid)
+    }
 }
 
 impl Drop for InternalConnection {
@@ -0,0 +1,89 @@
+use std::any::type_name;
+
+use bigdecimal::BigDecimal;
+
+use super::mismatch;
+use crate::value::{impl_from_value_array, impl_to_value_array};
+use crate::{Error, FromValue, Result, ToValue, Value};
+
+impl ToValue for BigDecimal {
+    fn to_value(&self) -> Value {
+        // `Display` may use exponent notation
+        Value::Decimal(self.to_plain_string())
+    }
+}
+
+impl FromValue for BigDecimal {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Integer(i) => Ok(BigDecimal::from(i)),
+            Value::Decimal(ref s) | Value::Text(ref s) => s.trim().parse().map_err(|e| {
+                Error::FromValue(format!(
+                    "Decimal `{}` cannot convert to {}: {}",
+                    s,
+                    type_name::<Self>(),
+                    e
+                ))
+            }),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl_to_value_array!(BigDecimal);
+impl_from_value_array!(BigDecimal);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for s in [
+            "0",
+            "-1.5",
+            "123456789.000123",
+            "123456789012345678901234567890.12345",
+        ] {
+            let d: BigDecimal = s.parse().unwrap();
+            assert_eq!(d.to_value(), Value::Decimal(s.to_string()));
+            assert_eq!(BigDecimal::from_value(d.to_value()).unwrap(), d);
+        }
+    }
+
+    #[test]
+    fn scale() {
+        // Bound without exponent notation
+        let d: BigDecimal = "1e-10".parse().unwrap();
+        assert_eq!(d.to_value(), Value::Decimal("0.0000000001".into()));
+        let d: BigDecimal = "1.5e10".parse().unwrap();
+        assert_eq!(d.to_value(), Value::Decimal("15000000000".into()));
+
+        let d = BigDecimal::from_value(Value::Decimal("1.2300".into())).unwrap();
+        assert_eq!(d.fractional_digit_count(), 4);
+        let d = BigDecimal::from_value(Value::Decimal("1.5E+3".into())).unwrap();
+        assert_eq!(d, BigDecimal::from(1500));
+    }
+
+    #[test]
+    fn integer_and_text() {
+        assert_eq!(
+            BigDecimal::from_value(Value::Integer(i64::MIN)).unwrap(),
+            BigDecimal::from(i64::MIN)
+        );
+        assert_eq!(
+            BigDecimal::from_value(Value::Text(" 12.5 ".into())).unwrap(),
+            BigDecimal::new(125.into(), 1)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let result = BigDecimal::from_value(Value::Decimal("1.2.3".into()));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = BigDecimal::from_value(Value::Text("abc".into()));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = BigDecimal::from_value(Value::Float(1.5));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+    }
+}
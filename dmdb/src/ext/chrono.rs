@@ -0,0 +1,253 @@
+use std::any::type_name;
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Timelike,
+};
+
+use super::mismatch;
+use crate::value::{impl_from_value_array, impl_to_value_array};
+use crate::{Error, FromValue, Result, ToValue, Value};
+
+fn invalid<T>(v: &Value) -> Error {
+    Error::FromValue(format!("Invalid {} `{:?}`", type_name::<T>(), v))
+}
+
+/// Year of the date, which fails if it's out of range of the DPI date types
+fn year<T: Datelike + std::fmt::Display>(t: &T) -> Result<u16> {
+    u16::try_from(t.year()).map_err(|_| {
+        Error::Parameter(format!(
+            "Year of {} `{}` is out of range",
+            type_name::<T>(),
+            t
+        ))
+    })
+}
+
+/// Microseconds of the time, leap second is clamped
+fn microsecond<T: Timelike>(t: &T) -> u32 {
+    (t.nanosecond() / 1000).min(999_999)
+}
+
+fn naive_date(year: u16, month: u8, day: u8) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+}
+
+fn naive_time(hour: u8, minute: u8, second: u8, microsecond: u32) -> Option<NaiveTime> {
+    NaiveTime::from_hms_micro_opt(hour as u32, minute as u32, second as u32, microsecond)
+}
+
+/// Dates with the year out of range are converted to text by `to_value`, and rejected by binding
+impl ToValue for NaiveDate {
+    fn to_value(&self) -> Value {
+        self.try_to_value()
+            .unwrap_or_else(|_| Value::Text(self.to_string()))
+    }
+
+    fn try_to_value(&self) -> Result<Value> {
+        Ok(Value::Date(
+            year(self)?,
+            self.month() as u8,
+            self.day() as u8,
+        ))
+    }
+}
+
+impl FromValue for NaiveDate {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Date(y, m, d) | Value::DateTime(y, m, d, ..) => {
+                naive_date(y, m, d).ok_or_else(|| invalid::<Self>(&v))
+            }
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl ToValue for NaiveTime {
+    fn to_value(&self) -> Value {
+        Value::Time(self.hour() as u8, self.minute() as u8, self.second() as u8)
+    }
+}
+
+impl FromValue for NaiveTime {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Time(h, i, s) => naive_time(h, i, s, 0).ok_or_else(|| invalid::<Self>(&v)),
+            Value::DateTime(_, _, _, h, i, s, us) => {
+                naive_time(h, i, s, us).ok_or_else(|| invalid::<Self>(&v))
+            }
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+/// Dates with the year out of range are converted to text by `to_value`, and rejected by binding
+impl ToValue for NaiveDateTime {
+    fn to_value(&self) -> Value {
+        self.try_to_value()
+            .unwrap_or_else(|_| Value::Text(self.to_string()))
+    }
+
+    fn try_to_value(&self) -> Result<Value> {
+        Ok(Value::DateTime(
+            year(self)?,
+            self.month() as u8,
+            self.day() as u8,
+            self.hour() as u8,
+            self.minute() as u8,
+            self.second() as u8,
+            microsecond(self),
+        ))
+    }
+}
+
+impl FromValue for NaiveDateTime {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::DateTime(y, m, d, h, i, s, us) => naive_date(y, m, d)
+                .zip(naive_time(h, i, s, us))
+                .map(|(date, time)| date.and_time(time))
+                .ok_or_else(|| invalid::<Self>(&v)),
+            Value::Date(y, m, d) => naive_date(y, m, d)
+                .map(|date| date.and_time(NaiveTime::MIN))
+                .ok_or_else(|| invalid::<Self>(&v)),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+/// Dates with the year out of range are converted to text by `to_value`, and rejected by binding
+impl ToValue for DateTime<FixedOffset> {
+    fn to_value(&self) -> Value {
+        self.try_to_value()
+            .unwrap_or_else(|_| Value::Text(self.to_string()))
+    }
+
+    fn try_to_value(&self) -> Result<Value> {
+        let local = self.naive_local();
+        Ok(Value::DateTimeTz(
+            year(&local)?,
+            local.month() as u8,
+            local.day() as u8,
+            local.hour() as u8,
+            local.minute() as u8,
+            local.second() as u8,
+            microsecond(&local),
+            (self.offset().fix().local_minus_utc() / 60) as i16,
+        ))
+    }
+}
+
+impl FromValue for DateTime<FixedOffset> {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::DateTimeTz(y, m, d, h, i, s, us, tz) => naive_date(y, m, d)
+                .zip(naive_time(h, i, s, us))
+                .zip(FixedOffset::east_opt(tz as i32 * 60))
+                .and_then(|((date, time), offset)| {
+                    offset.from_local_datetime(&date.and_time(time)).single()
+                })
+                .ok_or_else(|| invalid::<Self>(&v)),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl_to_value_array!(NaiveDate);
+impl_to_value_array!(NaiveTime);
+impl_to_value_array!(NaiveDateTime);
+impl_to_value_array!(DateTime<FixedOffset>);
+impl_from_value_array!(NaiveDate);
+impl_from_value_array!(NaiveTime);
+impl_from_value_array!(NaiveDateTime);
+impl_from_value_array!(DateTime<FixedOffset>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let date = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+        assert_eq!(date.to_value(), Value::Date(2021, 3, 1));
+        assert_eq!(NaiveDate::from_value(date.to_value()).unwrap(), date);
+
+        let dt = date.and_hms_micro_opt(15, 38, 0, 123456).unwrap();
+        assert_eq!(
+            dt.to_value(),
+            Value::DateTime(2021, 3, 1, 15, 38, 0, 123456)
+        );
+        assert_eq!(NaiveDateTime::from_value(dt.to_value()).unwrap(), dt);
+
+        let offset = FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap();
+        let tz = offset.from_local_datetime(&dt).unwrap();
+        assert_eq!(
+            tz.to_value(),
+            Value::DateTimeTz(2021, 3, 1, 15, 38, 0, 123456, -330)
+        );
+        assert_eq!(
+            DateTime::<FixedOffset>::from_value(tz.to_value()).unwrap(),
+            tz
+        );
+
+        let time = NaiveTime::from_hms_opt(16, 39, 1).unwrap();
+        assert_eq!(time.to_value(), Value::Time(16, 39, 1));
+        assert_eq!(NaiveTime::from_value(time.to_value()).unwrap(), time);
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let dates = vec![
+            NaiveDate::from_ymd_opt(2021, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+        ];
+        let v = dates.to_value();
+        assert_eq!(
+            v,
+            Value::Array(vec![Value::Date(2021, 3, 1), Value::Date(2022, 12, 31)])
+        );
+        assert_eq!(Vec::<NaiveDate>::from_value(v).unwrap(), dates);
+
+        let dates = vec![NaiveDate::from_ymd_opt(-1, 3, 1).unwrap()];
+        assert!(dates.try_to_value().is_err());
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        // Nanoseconds are truncated to microseconds, leap seconds are clamped
+        let dt = NaiveDate::from_ymd_opt(2021, 3, 1)
+            .unwrap()
+            .and_hms_nano_opt(15, 38, 59, 1_999_999_999)
+            .unwrap();
+        assert_eq!(
+            dt.to_value(),
+            Value::DateTime(2021, 3, 1, 15, 38, 59, 999_999)
+        );
+
+        let time = NaiveTime::from_value(Value::DateTime(2021, 3, 1, 15, 38, 0, 500_000)).unwrap();
+        assert_eq!(time, NaiveTime::from_hms_milli_opt(15, 38, 0, 500).unwrap());
+    }
+
+    #[test]
+    fn year_out_of_range() {
+        let date = NaiveDate::from_ymd_opt(-1, 3, 1).unwrap();
+        assert!(matches!(date.try_to_value(), Err(Error::Parameter(_))));
+        assert_eq!(date.to_value(), Value::Text(date.to_string()));
+
+        let dt = NaiveDate::from_ymd_opt(70000, 1, 1)
+            .unwrap()
+            .and_time(NaiveTime::MIN);
+        assert!(matches!(dt.try_to_value(), Err(Error::Parameter(_))));
+    }
+
+    #[test]
+    fn invalid_date() {
+        let result = NaiveDate::from_value(Value::Date(2021, 2, 30));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = NaiveDateTime::from_value(Value::DateTime(2021, 3, 1, 24, 0, 0, 0));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = NaiveDate::from_value(Value::Integer(1));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+    }
+}
@@ -0,0 +1,125 @@
+use std::{any::type_name, ops::Deref};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::mismatch;
+use crate::{Error, FromValue, Result, ToValue, Value, ValueRef};
+
+/// JSON document stored in VARCHAR/CLOB, serialized by serde_json
+///
+/// The document is serialized by [`Json::new`], so the errors are returned before binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Json<T> {
+    value: T,
+    text: String,
+}
+
+impl<T: Serialize> Json<T> {
+    pub fn new(value: T) -> Result<Self> {
+        let text = serde_json::to_string(&value).map_err(|e| {
+            Error::Parameter(format!("Serialize {} failed: {}", type_name::<T>(), e))
+        })?;
+
+        Ok(Self { value, text })
+    }
+}
+
+impl<T> Json<T> {
+    /// Serialized JSON text
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> ToValue for Json<T> {
+    fn to_value(&self) -> Value {
+        Value::Text(self.text.clone())
+    }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Text(&self.text))
+    }
+}
+
+impl<T: DeserializeOwned> FromValue for Json<T> {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Text(text) => Ok(Self {
+                value: parse_text(&text)?,
+                text,
+            }),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl ToValue for serde_json::Value {
+    fn to_value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl FromValue for serde_json::Value {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Null => Ok(serde_json::Value::Null),
+            v => parse(&v),
+        }
+    }
+}
+
+fn parse<T: DeserializeOwned>(v: &Value) -> Result<T> {
+    match v {
+        Value::Text(s) => parse_text(s),
+        _ => Err(mismatch::<T>(v)),
+    }
+}
+
+fn parse_text<T: DeserializeOwned>(s: &str) -> Result<T> {
+    serde_json::from_str(s).map_err(|e| {
+        Error::FromValue(format!(
+            "Text `{}` cannot convert to {}: {}",
+            s,
+            type_name::<T>(),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn serialize_eagerly() {
+        let json = Json::new(BTreeMap::from([("a", 1)])).unwrap();
+        assert_eq!(json.as_str(), r#"{"a":1}"#);
+        assert_eq!(json.to_value(), Value::Text(r#"{"a":1}"#.into()));
+
+        // Map keys must be strings
+        let result = Json::new(BTreeMap::from([(vec![1u8], 1)]));
+        assert!(matches!(result, Err(Error::Parameter(_))));
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = Json::<Vec<i32>>::from_value(Value::Text("[1,2]".into())).unwrap();
+        assert_eq!(*json, vec![1, 2]);
+        assert!(Json::<Vec<i32>>::from_value(Value::Text("{".into())).is_err());
+        assert!(Json::<Vec<i32>>::from_value(Value::Integer(1)).is_err());
+    }
+}
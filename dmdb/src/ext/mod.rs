@@ -0,0 +1,30 @@
+//! `ToValue`/`FromValue` implementations for types of optional crates
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "json")]
+pub(crate) mod json;
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+/// Error of converting a value of the wrong type
+// Unused when none of the features is enabled
+#[allow(dead_code)]
+pub(crate) fn mismatch<T>(v: &crate::Value) -> crate::Error {
+    crate::Error::FromValue(format!(
+        "Value type mismatch, cannot convert `{:?}` to {}",
+        v,
+        std::any::type_name::<T>()
+    ))
+}
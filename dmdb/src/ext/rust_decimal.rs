@@ -0,0 +1,101 @@
+use std::any::type_name;
+
+use rust_decimal::Decimal;
+
+use super::mismatch;
+use crate::value::{impl_from_value_array, impl_to_value_array};
+use crate::{Error, FromValue, Result, ToValue, Value};
+
+impl ToValue for Decimal {
+    fn to_value(&self) -> Value {
+        Value::Decimal(self.to_string())
+    }
+}
+
+impl FromValue for Decimal {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Integer(i) => Ok(Decimal::from(i)),
+            Value::Decimal(ref s) | Value::Text(ref s) => Decimal::from_str_exact(s.trim())
+                .map_err(|e| {
+                    Error::FromValue(format!(
+                        "Decimal `{}` cannot convert to {}: {}",
+                        s,
+                        type_name::<Self>(),
+                        e
+                    ))
+                }),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl_to_value_array!(Decimal);
+impl_from_value_array!(Decimal);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for s in [
+            "0",
+            "-1.5",
+            "123456789.000123",
+            "79228162514264337593543950335",
+        ] {
+            let d = Decimal::from_str_exact(s).unwrap();
+            assert_eq!(d.to_value(), Value::Decimal(s.to_string()));
+            assert_eq!(Decimal::from_value(d.to_value()).unwrap(), d);
+        }
+    }
+
+    #[test]
+    fn scale() {
+        // Trailing zeros of the scale are kept
+        let d = Decimal::from_value(Value::Decimal("1.2300".into())).unwrap();
+        assert_eq!(d.scale(), 4);
+        assert_eq!(d.to_value(), Value::Decimal("1.2300".into()));
+
+        // 28 is the maximum scale
+        let s = "0.0000000000000000000000000001";
+        assert_eq!(
+            Decimal::from_value(Value::Decimal(s.into()))
+                .unwrap()
+                .scale(),
+            28
+        );
+        let s = "0.00000000000000000000000000001";
+        let result = Decimal::from_value(Value::Decimal(s.into()));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+    }
+
+    #[test]
+    fn precision() {
+        let result = Decimal::from_value(Value::Decimal("79228162514264337593543950336".into()));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+    }
+
+    #[test]
+    fn integer_and_text() {
+        assert_eq!(
+            Decimal::from_value(Value::Integer(i64::MIN)).unwrap(),
+            Decimal::from(i64::MIN)
+        );
+        assert_eq!(
+            Decimal::from_value(Value::Text(" 12.5 ".into())).unwrap(),
+            Decimal::new(125, 1)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let result = Decimal::from_value(Value::Decimal("1.2.3".into()));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = Decimal::from_value(Value::Text("abc".into()));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = Decimal::from_value(Value::Float(1.5));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+    }
+}
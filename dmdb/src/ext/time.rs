@@ -0,0 +1,218 @@
+use std::any::type_name;
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use super::mismatch;
+use crate::value::{impl_from_value_array, impl_to_value_array};
+use crate::{Error, FromValue, Result, ToValue, Value};
+
+fn invalid<T>(v: &Value, e: time::error::ComponentRange) -> Error {
+    Error::FromValue(format!("Invalid {} `{:?}`: {}", type_name::<T>(), v, e))
+}
+
+fn date(year: u16, month: u8, day: u8) -> std::result::Result<Date, time::error::ComponentRange> {
+    Date::from_calendar_date(year as i32, Month::try_from(month)?, day)
+}
+
+/// Year of the date, which fails if it's out of range of the DPI date types
+fn year<T: std::fmt::Display>(t: &T, year: i32) -> Result<u16> {
+    u16::try_from(year).map_err(|_| {
+        Error::Parameter(format!(
+            "Year of {} `{}` is out of range",
+            type_name::<T>(),
+            t
+        ))
+    })
+}
+
+/// Dates with the year out of range are converted to text by `to_value`, and rejected by binding
+impl ToValue for Date {
+    fn to_value(&self) -> Value {
+        self.try_to_value()
+            .unwrap_or_else(|_| Value::Text(self.to_string()))
+    }
+
+    fn try_to_value(&self) -> Result<Value> {
+        Ok(Value::Date(
+            year(self, self.year())?,
+            self.month() as u8,
+            self.day(),
+        ))
+    }
+}
+
+impl FromValue for Date {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Date(y, m, d) | Value::DateTime(y, m, d, ..) => {
+                date(y, m, d).map_err(|e| invalid::<Self>(&v, e))
+            }
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl ToValue for Time {
+    fn to_value(&self) -> Value {
+        Value::Time(self.hour(), self.minute(), self.second())
+    }
+}
+
+impl FromValue for Time {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Time(h, i, s) => Time::from_hms(h, i, s).map_err(|e| invalid::<Self>(&v, e)),
+            Value::DateTime(_, _, _, h, i, s, us) => {
+                Time::from_hms_micro(h, i, s, us).map_err(|e| invalid::<Self>(&v, e))
+            }
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+/// Dates with the year out of range are converted to text by `to_value`, and rejected by binding
+impl ToValue for PrimitiveDateTime {
+    fn to_value(&self) -> Value {
+        self.try_to_value()
+            .unwrap_or_else(|_| Value::Text(self.to_string()))
+    }
+
+    fn try_to_value(&self) -> Result<Value> {
+        Ok(Value::DateTime(
+            year(self, self.year())?,
+            self.month() as u8,
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.microsecond(),
+        ))
+    }
+}
+
+impl FromValue for PrimitiveDateTime {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::DateTime(y, m, d, h, i, s, us) => date(y, m, d)
+                .and_then(|date| Ok(date.with_time(Time::from_hms_micro(h, i, s, us)?)))
+                .map_err(|e| invalid::<Self>(&v, e)),
+            Value::Date(y, m, d) => date(y, m, d)
+                .map(Date::midnight)
+                .map_err(|e| invalid::<Self>(&v, e)),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+/// Dates with the year out of range are converted to text by `to_value`, and rejected by binding
+impl ToValue for OffsetDateTime {
+    fn to_value(&self) -> Value {
+        self.try_to_value()
+            .unwrap_or_else(|_| Value::Text(self.to_string()))
+    }
+
+    fn try_to_value(&self) -> Result<Value> {
+        Ok(Value::DateTimeTz(
+            year(self, self.year())?,
+            self.month() as u8,
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.microsecond(),
+            self.offset().whole_minutes(),
+        ))
+    }
+}
+
+impl FromValue for OffsetDateTime {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::DateTimeTz(y, m, d, h, i, s, us, tz) => date(y, m, d)
+                .and_then(|date| {
+                    let time = Time::from_hms_micro(h, i, s, us)?;
+                    let offset = UtcOffset::from_whole_seconds(tz as i32 * 60)?;
+                    Ok(date.with_time(time).assume_offset(offset))
+                })
+                .map_err(|e| invalid::<Self>(&v, e)),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl_to_value_array!(Date);
+impl_to_value_array!(Time);
+impl_to_value_array!(PrimitiveDateTime);
+impl_to_value_array!(OffsetDateTime);
+impl_from_value_array!(Date);
+impl_from_value_array!(Time);
+impl_from_value_array!(PrimitiveDateTime);
+impl_from_value_array!(OffsetDateTime);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let date = Date::from_calendar_date(2021, Month::March, 1).unwrap();
+        assert_eq!(date.to_value(), Value::Date(2021, 3, 1));
+        assert_eq!(Date::from_value(date.to_value()).unwrap(), date);
+
+        let dt = date.with_hms_micro(15, 38, 0, 123456).unwrap();
+        assert_eq!(
+            dt.to_value(),
+            Value::DateTime(2021, 3, 1, 15, 38, 0, 123456)
+        );
+        assert_eq!(PrimitiveDateTime::from_value(dt.to_value()).unwrap(), dt);
+
+        let tz = dt.assume_offset(UtcOffset::from_hms(-5, -30, 0).unwrap());
+        assert_eq!(
+            tz.to_value(),
+            Value::DateTimeTz(2021, 3, 1, 15, 38, 0, 123456, -330)
+        );
+        assert_eq!(OffsetDateTime::from_value(tz.to_value()).unwrap(), tz);
+
+        let time = Time::from_hms(16, 39, 1).unwrap();
+        assert_eq!(time.to_value(), Value::Time(16, 39, 1));
+        assert_eq!(Time::from_value(time.to_value()).unwrap(), time);
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        // Nanoseconds are truncated to microseconds
+        let dt = Date::from_calendar_date(2021, Month::March, 1)
+            .unwrap()
+            .with_hms_nano(15, 38, 59, 999_999_999)
+            .unwrap();
+        assert_eq!(
+            dt.to_value(),
+            Value::DateTime(2021, 3, 1, 15, 38, 59, 999_999)
+        );
+
+        let time = Time::from_value(Value::DateTime(2021, 3, 1, 15, 38, 0, 500_000)).unwrap();
+        assert_eq!(time, Time::from_hms_milli(15, 38, 0, 500).unwrap());
+    }
+
+    #[test]
+    fn year_out_of_range() {
+        let date = Date::from_calendar_date(-1, Month::March, 1).unwrap();
+        assert!(matches!(date.try_to_value(), Err(Error::Parameter(_))));
+        assert_eq!(date.to_value(), Value::Text(date.to_string()));
+
+        let dt = date.midnight().assume_utc();
+        assert!(matches!(dt.try_to_value(), Err(Error::Parameter(_))));
+    }
+
+    #[test]
+    fn invalid_date() {
+        let result = Date::from_value(Value::Date(2021, 2, 30));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = PrimitiveDateTime::from_value(Value::DateTime(2021, 3, 1, 24, 0, 0, 0));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = OffsetDateTime::from_value(Value::DateTimeTz(2021, 3, 1, 0, 0, 0, 0, 60 * 26));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = Date::from_value(Value::Integer(1));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+    }
+}
@@ -0,0 +1,103 @@
+use std::any::type_name;
+
+use uuid::{
+    fmt::{Hyphenated, Simple},
+    Uuid,
+};
+
+use super::mismatch;
+use crate::value::{impl_from_value_array, impl_to_value_array};
+use crate::{Error, FromValue, Result, ToValue, Value};
+
+/// Bound as `BINARY(16)`, use [`Uuid::hyphenated`] or [`Uuid::simple`] to bind as text
+impl ToValue for Uuid {
+    fn to_value(&self) -> Value {
+        Value::Blob(self.as_bytes().to_vec())
+    }
+}
+
+/// Bound as `CHAR(36)` text
+impl ToValue for Hyphenated {
+    fn to_value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+/// Bound as `CHAR(32)` text
+impl ToValue for Simple {
+    fn to_value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl FromValue for Uuid {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Text(ref s) => Uuid::parse_str(s.trim_end()).map_err(|e| {
+                Error::FromValue(format!(
+                    "Text `{}` cannot convert to {}: {}",
+                    s,
+                    type_name::<Self>(),
+                    e
+                ))
+            }),
+            Value::Blob(ref b) => Uuid::from_slice(b).map_err(|e| {
+                Error::FromValue(format!(
+                    "Blob `{:?}` cannot convert to {}: {}",
+                    b,
+                    type_name::<Self>(),
+                    e
+                ))
+            }),
+            _ => Err(mismatch::<Self>(&v)),
+        }
+    }
+}
+
+impl_to_value_array!(Uuid);
+impl_from_value_array!(Uuid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    #[test]
+    fn round_trip() {
+        let uuid = Uuid::parse_str(TEXT).unwrap();
+        assert_eq!(uuid.to_value(), Value::Blob(uuid.as_bytes().to_vec()));
+        assert_eq!(Uuid::from_value(uuid.to_value()).unwrap(), uuid);
+
+        assert_eq!(uuid.hyphenated().to_value(), Value::Text(TEXT.into()));
+        assert_eq!(
+            Uuid::from_value(uuid.hyphenated().to_value()).unwrap(),
+            uuid
+        );
+
+        let simple = uuid.simple().to_value();
+        assert_eq!(simple, Value::Text(TEXT.replace('-', "")));
+        assert_eq!(Uuid::from_value(simple).unwrap(), uuid);
+    }
+
+    #[test]
+    fn padded_text() {
+        // `CHAR` columns wider than the text are padded with spaces
+        let uuid = Uuid::from_value(Value::Text(format!("{}    ", TEXT))).unwrap();
+        assert_eq!(uuid, Uuid::parse_str(TEXT).unwrap());
+    }
+
+    #[test]
+    fn malformed() {
+        let result = Uuid::from_value(Value::Text("67e55044-10b1-426f-9247".into()));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = Uuid::from_value(Value::Text(TEXT.replace('e', "x")));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = Uuid::from_value(Value::Blob(vec![0; 15]));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = Uuid::from_value(Value::Blob(vec![0; 17]));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+        let result = Uuid::from_value(Value::Integer(1));
+        assert!(matches!(result, Err(Error::FromValue(_))));
+    }
+}
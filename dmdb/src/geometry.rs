@@ -0,0 +1,187 @@
+use crate::{Object, Value};
+
+/// Schema of the DMGEO types
+const GEOMETRY_SCHEMA: &str = "SYSGEO";
+
+/// Base type of the DMGEO geometries, the subtypes are named `ST_POINT`, `ST_POLYGON`, ...
+const GEOMETRY_TYPE: &str = "ST_GEOMETRY";
+
+const SRID_ATTRIBUTE: &str = "SRID";
+
+const WKB_ATTRIBUTE: &str = "GEO_WKB";
+
+/// Convert DMGEO object to [`Value::Geometry`], other objects are returned as is
+pub(crate) fn from_object(object: Object) -> Value {
+    let is_geometry = object
+        .schema
+        .as_deref()
+        .is_some_and(|s| s.eq_ignore_ascii_case(GEOMETRY_SCHEMA))
+        && object.name.to_ascii_uppercase().starts_with("ST_");
+    if !is_geometry {
+        return Value::Object(object);
+    }
+
+    let srid = match object.get_value(SRID_ATTRIBUTE) {
+        Some(Value::Integer(srid)) => *srid as i32,
+        _ => 0,
+    };
+    match object.get_value(WKB_ATTRIBUTE) {
+        Some(Value::Blob(wkb)) => Value::Geometry(srid, wkb.clone()),
+        Some(Value::Null) => Value::Null,
+        _ => Value::Object(object),
+    }
+}
+
+/// Build the `SYSGEO.ST_GEOMETRY` object to bind, its attributes are shared by the subtypes
+pub(crate) fn to_object(srid: i32, wkb: &[u8]) -> Object {
+    Object::with_schema(GEOMETRY_SCHEMA, GEOMETRY_TYPE)
+        .attribute(SRID_ATTRIBUTE, srid)
+        .attribute(WKB_ATTRIBUTE, wkb)
+}
+
+#[cfg(feature = "geo")]
+pub use geo::Srid;
+
+/// `ToValue`/`FromValue` of the geo-types geometries, the bare geometries are bound with SRID 0
+/// and read without the SRID, use [`Srid`] to keep it
+#[cfg(feature = "geo")]
+mod geo {
+    use geo_types::{
+        Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+        Point, Polygon, Rect, Triangle,
+    };
+
+    use crate::{utils::wkb, Error, FromValue, Result, ToValue, Value};
+
+    /// Geometry with its spatial reference id, e.g. `Srid(4326, point)`
+    ///
+    /// The bare geo-types geometries are bound with SRID 0 and the stored SRID is dropped when
+    /// they are read.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Srid<G>(pub i32, pub G);
+
+    impl ToValue for Geometry<f64> {
+        fn to_value(&self) -> Value {
+            Value::Geometry(0, wkb::encode(self))
+        }
+    }
+
+    impl ToValue for Srid<Geometry<f64>> {
+        fn to_value(&self) -> Value {
+            Value::Geometry(self.0, wkb::encode(&self.1))
+        }
+    }
+
+    macro_rules! impl_to_value_geometry {
+        ($ty:ident) => {
+            impl ToValue for $ty<f64> {
+                fn to_value(&self) -> Value {
+                    Value::Geometry(0, wkb::encode(&Geometry::$ty(self.clone())))
+                }
+            }
+
+            impl ToValue for Srid<$ty<f64>> {
+                fn to_value(&self) -> Value {
+                    Value::Geometry(self.0, wkb::encode(&Geometry::$ty(self.1.clone())))
+                }
+            }
+        };
+    }
+
+    impl_to_value_geometry!(Point);
+    impl_to_value_geometry!(Line);
+    impl_to_value_geometry!(LineString);
+    impl_to_value_geometry!(Polygon);
+    impl_to_value_geometry!(MultiPoint);
+    impl_to_value_geometry!(MultiLineString);
+    impl_to_value_geometry!(MultiPolygon);
+    impl_to_value_geometry!(GeometryCollection);
+    impl_to_value_geometry!(Rect);
+    impl_to_value_geometry!(Triangle);
+
+    impl FromValue for Geometry<f64> {
+        fn from_value(v: Value) -> Result<Self> {
+            match v {
+                // Also accept the result of `ST_AsBinary`
+                Value::Geometry(_, ref data) | Value::Blob(ref data) => wkb::decode(data)
+                    .ok_or_else(|| Error::FromValue(format!("Parse geometry `{:?}` failed", v))),
+                _ => Err(Error::FromValue(format!(
+                    "Value type mismatch, cannot convert `{:?}` to {}",
+                    v,
+                    std::any::type_name::<Self>()
+                ))),
+            }
+        }
+    }
+
+    /// SRID of the geometry value, `ST_AsBinary` results have none
+    fn srid(v: &Value) -> i32 {
+        match v {
+            Value::Geometry(srid, _) => *srid,
+            _ => 0,
+        }
+    }
+
+    impl FromValue for Srid<Geometry<f64>> {
+        fn from_value(v: Value) -> Result<Self> {
+            Ok(Self(srid(&v), Geometry::from_value(v)?))
+        }
+    }
+
+    macro_rules! impl_from_value_geometry {
+        ($ty:ident) => {
+            impl FromValue for Srid<$ty<f64>> {
+                fn from_value(v: Value) -> Result<Self> {
+                    Ok(Self(srid(&v), $ty::from_value(v)?))
+                }
+            }
+
+            impl FromValue for $ty<f64> {
+                fn from_value(v: Value) -> Result<Self> {
+                    Geometry::from_value(v)?.try_into().map_err(|e| {
+                        Error::FromValue(format!(
+                            "Cannot convert geometry to {}: {}",
+                            std::any::type_name::<Self>(),
+                            e
+                        ))
+                    })
+                }
+            }
+        };
+    }
+
+    impl_from_value_geometry!(Point);
+    impl_from_value_geometry!(LineString);
+    impl_from_value_geometry!(Polygon);
+    impl_from_value_geometry!(MultiPoint);
+    impl_from_value_geometry!(MultiLineString);
+    impl_from_value_geometry!(MultiPolygon);
+    impl_from_value_geometry!(GeometryCollection);
+
+    #[cfg(test)]
+    mod tests {
+        use geo_types::point;
+
+        use super::*;
+
+        #[test]
+        fn srid_round_trip() {
+            let point = point!(x: 116.4, y: 39.9);
+
+            let value = Srid(4326, point).to_value();
+            assert!(matches!(value, Value::Geometry(4326, _)));
+            assert_eq!(
+                Srid::<Point<f64>>::from_value(value.clone()).unwrap(),
+                Srid(4326, point)
+            );
+            assert_eq!(
+                Srid::<Geometry<f64>>::from_value(value.clone()).unwrap(),
+                Srid(4326, Geometry::Point(point))
+            );
+            assert_eq!(Point::from_value(value).unwrap(), point);
+
+            // Bare geometries have SRID 0
+            assert!(matches!(point.to_value(), Value::Geometry(0, _)));
+        }
+    }
+}
@@ -1,21 +1,39 @@
+mod bfile;
 mod connection;
 mod error;
+mod ext;
+mod geometry;
+mod lob;
+mod object;
 mod params;
 mod row;
+mod rowid;
 mod rows;
+mod sql_type;
 mod statement;
 mod transaction;
 mod utils;
 mod value;
 
+pub use bfile::{BFile, BFileReader};
 pub use connection::Connection;
 pub use error::{Error, Result};
-pub use params::Params;
+#[cfg(feature = "json")]
+pub use ext::json::Json;
+#[cfg(feature = "geo")]
+pub use geometry::Srid;
+pub use lob::{Lob, LobReader, LobStream, StreamSource};
+pub use object::Object;
+pub use params::{Params, ToParam};
 pub use row::Row;
+pub use rowid::RowId;
 pub use rows::Rows;
-pub use statement::Statement;
+pub use sql_type::SqlType;
+pub use statement::{ColumnInfo, ParamInfo, Statement};
 pub use transaction::Transaction;
-pub use value::{DateTimeTuple, FromValue, ToValue, Value, ValueType};
+pub use value::{
+    DateTimeTuple, DateTimeTzTuple, DateTuple, FromValue, FromValueRef, IntervalDaySecondTuple,
+    IntervalYearMonthTuple, Lossy, TimeTuple, TimeTzTuple, ToValue, Value, ValueRef, ValueType,
+};
 
 pub(crate) use connection::InternalConnection;
-pub(crate) use statement::ColumnInfo;
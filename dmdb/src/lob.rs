@@ -0,0 +1,532 @@
+use std::{
+    cell::RefCell,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+use crate::{utils::error::error_check, Error, Result, Statement, ToParam, Value};
+
+/// Chunk size of data sent by `dpi_put_data`
+const PUT_DATA_CHUNK_LEN: usize = 64 * 1024;
+
+/// Owned LOB locator handle
+pub(crate) struct LobLocator(pub(crate) dmdb_sys::dhloblctr);
+
+impl LobLocator {
+    pub(crate) fn alloc(hstmt: dmdb_sys::dhstmt) -> Result<Self> {
+        let mut lob: dmdb_sys::dhloblctr = std::ptr::null_mut();
+        unsafe {
+            let rt = dmdb_sys::dpi_alloc_lob_locator(hstmt, &mut lob);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, hstmt, msg => Error::Statement(format!("Allocate lob locator failed: {}", msg)));
+        }
+
+        Ok(Self(lob))
+    }
+}
+
+impl Drop for LobLocator {
+    fn drop(&mut self) {
+        unsafe {
+            dmdb_sys::dpi_free_lob_locator(self.0);
+        }
+    }
+}
+
+/// Streaming reader of a BLOB/CLOB column, see [`Rows::stream_lob`](crate::Rows::stream_lob)
+///
+/// BLOB data is read in bytes and supports [`Seek`], CLOB data is read as UTF-8 text and only
+/// supports sequential reading.
+pub struct LobReader<'row> {
+    lob: &'row LobLocator,
+    is_clob: bool,
+    /// Total length, bytes for BLOB and chars for CLOB
+    len: u64,
+    /// 0-based read position, bytes for BLOB and chars for CLOB
+    pos: u64,
+    /// Decoded CLOB bytes not yet returned to the caller
+    pending: Vec<u8>,
+    pending_pos: usize,
+}
+
+impl<'row> LobReader<'row> {
+    pub(crate) fn new(lob: &'row LobLocator, is_clob: bool) -> Result<Self> {
+        let mut len: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_lob_get_length(lob.0, &mut len);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_LOB_LOCATOR, lob.0, msg => Error::Statement(format!("Get lob length failed: {}", msg)));
+        }
+
+        Ok(Self {
+            lob,
+            is_clob,
+            len: len.max(0) as u64,
+            pos: 0,
+            pending: vec![],
+            pending_pos: 0,
+        })
+    }
+
+    /// Total length, bytes for BLOB and chars for CLOB
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn read_lob(
+        &mut self,
+        ctype: u32,
+        to_read: u64,
+        buf: &mut [u8],
+    ) -> io::Result<dmdb_sys::slength> {
+        let mut data_get: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_lob_read(
+                self.lob.0,
+                (self.pos + 1) as dmdb_sys::ulength,
+                ctype as dmdb_sys::sdint2,
+                to_read as dmdb_sys::slength,
+                buf.as_mut_ptr() as dmdb_sys::dpointer,
+                buf.len() as dmdb_sys::slength,
+                &mut data_get,
+            );
+            if rt != dmdb_sys::DSQL_NO_DATA as dmdb_sys::DPIRETURN {
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_LOB_LOCATOR, self.lob.0, msg => io::Error::other(msg));
+            }
+        }
+
+        Ok(data_get.max(0))
+    }
+}
+
+impl Read for LobReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if !self.is_clob {
+            let remain = self.len.saturating_sub(self.pos);
+            if remain == 0 {
+                return Ok(0);
+            }
+
+            let to_read = remain.min(buf.len() as u64);
+            let n = self.read_lob(
+                dmdb_sys::DSQL_C_BINARY,
+                to_read,
+                &mut buf[..to_read as usize],
+            )?;
+            self.pos += n as u64;
+
+            return Ok(n as usize);
+        }
+
+        // Read next chars, each UTF-8 char takes up to 4 bytes plus the null terminator
+        if self.pending_pos >= self.pending.len() {
+            let remain = self.len.saturating_sub(self.pos);
+            if remain == 0 {
+                return Ok(0);
+            }
+
+            let to_read = remain.min((buf.len() as u64 / 4).max(1));
+            let mut tmp = vec![0u8; to_read as usize * 4 + 1];
+            let n = self.read_lob(dmdb_sys::DSQL_C_NCHAR, to_read, &mut tmp)?;
+            tmp.truncate(clob_data_len(n, tmp.len()));
+            // The CLOB position is in chars, not the bytes read
+            self.pos += utf8_char_count(&tmp);
+            self.pending = tmp;
+            self.pending_pos = 0;
+        }
+
+        let pending = &self.pending[self.pending_pos..];
+        let n = pending.len().min(buf.len());
+        buf[..n].copy_from_slice(&pending[..n]);
+        self.pending_pos += n;
+
+        Ok(n)
+    }
+}
+
+impl Seek for LobReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        if self.is_clob {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Seek is not supported for CLOB",
+            ));
+        }
+
+        let new_pos = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::End(n) => self.len.checked_add_signed(n),
+            SeekFrom::Current(n) => self.pos.checked_add_signed(n),
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Seek to a negative or overflowing position",
+            )
+        })?;
+        self.pos = new_pos;
+
+        Ok(new_pos)
+    }
+}
+
+/// LOB locator of a BLOB/CLOB value, see [`Row::get_lob_locator`](crate::Row::get_lob_locator)
+///
+/// Offsets and lengths are in bytes for BLOB and in chars for CLOB, CLOB data is UTF-8 text.
+/// It can be bound as a parameter to copy the LOB on the server side.
+pub struct Lob<'row> {
+    lob: &'row LobLocator,
+    is_clob: bool,
+}
+
+impl<'row> Lob<'row> {
+    pub(crate) fn new(lob: &'row LobLocator, is_clob: bool) -> Self {
+        Self { lob, is_clob }
+    }
+
+    pub fn is_clob(&self) -> bool {
+        self.is_clob
+    }
+
+    /// Total length, bytes for BLOB and chars for CLOB
+    pub fn len(&self) -> Result<u64> {
+        let mut len: dmdb_sys::sdint8 = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_lob_get_length2(self.lob.0, &mut len);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_LOB_LOCATOR, self.lob.0, msg => Error::Statement(format!("Get lob length failed: {}", msg)));
+        }
+
+        Ok(len.max(0) as u64)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Read at most `len` bytes/chars from the 0-based `offset`
+    pub fn read(&self, offset: u64, len: usize) -> Result<Vec<u8>> {
+        // Each UTF-8 char takes up to 4 bytes plus the null terminator
+        let (ctype, buf_len) = if self.is_clob {
+            (dmdb_sys::DSQL_C_NCHAR, len * 4 + 1)
+        } else {
+            (dmdb_sys::DSQL_C_BINARY, len)
+        };
+        if len == 0 {
+            return Ok(vec![]);
+        }
+        let mut buf = vec![0u8; buf_len];
+
+        let mut data_get: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_lob_read2(
+                self.lob.0,
+                offset + 1,
+                ctype as dmdb_sys::sdint2,
+                len as dmdb_sys::slength,
+                buf.as_mut_ptr() as dmdb_sys::dpointer,
+                buf_len as dmdb_sys::slength,
+                &mut data_get,
+            );
+            if rt != dmdb_sys::DSQL_NO_DATA as dmdb_sys::DPIRETURN {
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_LOB_LOCATOR, self.lob.0, msg => Error::Statement(format!("Read lob failed: {}", msg)));
+            }
+        }
+
+        let bytes = if self.is_clob {
+            clob_data_len(data_get, buf.len())
+        } else {
+            data_get.max(0) as usize
+        };
+        buf.truncate(bytes);
+
+        Ok(buf)
+    }
+
+    /// Write data at the 0-based `offset`, returns the written bytes/chars
+    pub fn write(&self, offset: u64, data: &[u8]) -> Result<u64> {
+        let ctype = if self.is_clob {
+            dmdb_sys::DSQL_C_NCHAR
+        } else {
+            dmdb_sys::DSQL_C_BINARY
+        };
+
+        let mut written: dmdb_sys::ulength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_lob_write2(
+                self.lob.0,
+                offset + 1,
+                ctype as dmdb_sys::sdint2,
+                data.as_ptr() as dmdb_sys::dpointer,
+                data.len() as dmdb_sys::ulength,
+                &mut written,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_LOB_LOCATOR, self.lob.0, msg => Error::Statement(format!("Write lob failed: {}", msg)));
+        }
+
+        Ok(written as u64)
+    }
+
+    /// Append data to the end, returns the written bytes/chars
+    pub fn append(&self, data: &[u8]) -> Result<u64> {
+        self.write(self.len()?, data)
+    }
+
+    /// Truncate to `len` bytes/chars, returns the new length
+    pub fn truncate(&self, len: u64) -> Result<u64> {
+        let mut new_len: dmdb_sys::udint8 = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_lob_truncate2(self.lob.0, len, &mut new_len);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_LOB_LOCATOR, self.lob.0, msg => Error::Statement(format!("Truncate lob failed: {}", msg)));
+        }
+
+        Ok(new_len)
+    }
+
+    pub(crate) fn handle(&self) -> &dmdb_sys::dhloblctr {
+        &self.lob.0
+    }
+}
+
+/// LOB locators are bound as handles, they have no [`Value`](crate::Value)
+impl ToParam for Lob<'_> {
+    fn param_value(&self) -> Result<Value> {
+        Err(Error::Parameter("Lob is bound as a locator".into()))
+    }
+
+    fn as_lob(&self) -> Option<&Lob<'_>> {
+        Some(self)
+    }
+}
+
+impl ToParam for Option<Lob<'_>> {
+    fn param_value(&self) -> Result<Value> {
+        match self {
+            Some(lob) => lob.param_value(),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn as_lob(&self) -> Option<&Lob<'_>> {
+        self.as_ref()
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Source of a data-at-execution parameter, the data is sent during [`Statement::execute`]
+///
+/// This trait is sealed, [`LobStream`] is the only implementation.
+pub trait StreamSource: sealed::Sealed {
+    /// Whether the target is a CLOB, otherwise a BLOB
+    fn is_clob(&self) -> bool;
+
+    /// Send all data of the parameter by `dpi_put_data`
+    fn put_data(&self, stmt: &Statement) -> Result<()>;
+}
+
+/// BLOB/CLOB parameter streamed from a reader with constant memory
+///
+/// ```ignore
+/// let file = std::fs::File::open("document.pdf")?;
+/// conn.execute("INSERT INTO docs (data) VALUES (?)", params![LobStream::blob(file)])?;
+/// ```
+pub struct LobStream<R> {
+    reader: RefCell<R>,
+    is_clob: bool,
+}
+
+impl<R: Read> LobStream<R> {
+    /// Stream binary data into a BLOB
+    pub fn blob(reader: R) -> Self {
+        Self {
+            reader: RefCell::new(reader),
+            is_clob: false,
+        }
+    }
+
+    /// Stream UTF-8 text into a CLOB
+    pub fn clob(reader: R) -> Self {
+        Self {
+            reader: RefCell::new(reader),
+            is_clob: true,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+}
+
+impl<R: Read> sealed::Sealed for LobStream<R> {}
+
+impl<R: Read> StreamSource for LobStream<R> {
+    fn is_clob(&self) -> bool {
+        self.is_clob
+    }
+
+    fn put_data(&self, stmt: &Statement) -> Result<()> {
+        let mut reader = self
+            .reader
+            .try_borrow_mut()
+            .map_err(|_| Error::Parameter("Stream is already in use".into()))?;
+        let mut buf = vec![0u8; PUT_DATA_CHUNK_LEN];
+        // Length of data kept from the last chunk
+        let mut kept = 0;
+        let mut sent = false;
+
+        loop {
+            let n = match reader.read(&mut buf[kept..]) {
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(Error::Parameter(format!("Read stream failed: {e}"))),
+            };
+            let end = kept + n;
+            if n == 0 && end == 0 && sent {
+                break;
+            }
+
+            // Do not split UTF-8 chars of CLOB between chunks
+            let send_len = if self.is_clob && n != 0 {
+                utf8_complete_len(&buf[..end])
+            } else {
+                end
+            };
+            if send_len == 0 && n != 0 {
+                kept = end;
+                continue;
+            }
+
+            unsafe {
+                let rt = dmdb_sys::dpi_put_data(
+                    stmt.hstmt,
+                    buf.as_mut_ptr() as dmdb_sys::dpointer,
+                    send_len as dmdb_sys::slength,
+                );
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, stmt.hstmt, msg => Error::Parameter(format!("Put stream data failed: {}", msg)));
+            }
+            sent = true;
+
+            buf.copy_within(send_len..end, 0);
+            kept = end - send_len;
+            if n == 0 {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Streams are bound as data-at-execution parameters, they have no [`Value`](crate::Value)
+impl<R: Read> ToParam for LobStream<R> {
+    fn param_value(&self) -> Result<Value> {
+        Err(Error::Parameter("LobStream is bound as a stream".into()))
+    }
+
+    fn as_stream(&self) -> Option<&dyn StreamSource> {
+        Some(self)
+    }
+}
+
+impl<R: Read> ToParam for Option<LobStream<R>> {
+    fn param_value(&self) -> Result<Value> {
+        match self {
+            Some(stream) => stream.param_value(),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn as_stream(&self) -> Option<&dyn StreamSource> {
+        self.as_ref().map(|stream| stream as &dyn StreamSource)
+    }
+}
+
+/// Length of the CLOB data read into a buffer of `buf_len` bytes, which keeps a null terminator
+fn clob_data_len(data_get: dmdb_sys::slength, buf_len: usize) -> usize {
+    (data_get.max(0) as usize).min(buf_len.saturating_sub(1))
+}
+
+/// Count the UTF-8 chars, i.e. the bytes which are not continuation bytes
+fn utf8_char_count(bytes: &[u8]) -> u64 {
+    bytes.iter().filter(|b| *b & 0xC0 != 0x80).count() as u64
+}
+
+/// Get the length of leading bytes that only contain complete UTF-8 chars
+fn utf8_complete_len(bytes: &[u8]) -> usize {
+    let len = bytes.len();
+    for i in 1..=len.min(4) {
+        let b = bytes[len - i];
+        // Continuation byte
+        if b & 0xC0 == 0x80 {
+            continue;
+        }
+
+        let width = match b {
+            0xF0.. => 4,
+            0xE0.. => 3,
+            0xC0.. => 2,
+            _ => 1,
+        };
+        return if width > i { len - i } else { len };
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_count() {
+        assert_eq!(utf8_char_count(b""), 0);
+        assert_eq!(utf8_char_count("a中文😀\0b".as_bytes()), 6);
+    }
+
+    #[test]
+    fn data_len() {
+        assert_eq!(clob_data_len(5, 10), 5);
+        assert_eq!(clob_data_len(10, 10), 9);
+        assert_eq!(clob_data_len(-1, 10), 0);
+    }
+
+    #[test]
+    fn read_multibyte_clob_in_chunks() {
+        let text = (0..100).map(|i| format!("{i}中文😀\0")).collect::<String>();
+        let chars = text.chars().collect::<Vec<_>>();
+
+        // Read chunks like `LobReader` from a server that returns `to_read` chars at `pos`
+        for to_read in [1, 2, 3, 7, 64] {
+            let mut pos = 0;
+            let mut read = vec![];
+            while pos < chars.len() as u64 {
+                let chunk = chars[pos as usize..]
+                    .iter()
+                    .take(to_read)
+                    .collect::<String>();
+                let mut tmp = vec![0u8; to_read * 4 + 1];
+                tmp[..chunk.len()].copy_from_slice(chunk.as_bytes());
+
+                tmp.truncate(clob_data_len(chunk.len() as _, tmp.len()));
+                pos += utf8_char_count(&tmp);
+                read.extend(tmp);
+            }
+            assert_eq!(String::from_utf8(read).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn complete_len() {
+        let bytes = "a中".as_bytes();
+        assert_eq!(utf8_complete_len(bytes), 4);
+        assert_eq!(utf8_complete_len(&bytes[..3]), 1);
+        assert_eq!(utf8_complete_len(&bytes[..1]), 1);
+    }
+}
@@ -0,0 +1,670 @@
+use std::mem::{size_of, size_of_val};
+
+use crate::{
+    geometry,
+    row::{parse_value, sql_value_type},
+    utils::{datetime, error::error_check},
+    Error, FromValue, InternalConnection, Result, ToValue, Value, ValueRef,
+};
+
+/// Instance of a user-defined object type, e.g. CLASS or RECORD
+#[derive(Debug, Clone, PartialEq)]
+pub struct Object {
+    /// Schema of the type, `None` means the current schema
+    pub schema: Option<String>,
+    pub name: String,
+    /// Attributes in declaration order
+    pub attributes: Vec<(String, Value)>,
+}
+
+impl Object {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            schema: None,
+            name: name.into(),
+            attributes: vec![],
+        }
+    }
+
+    pub fn with_schema<S: Into<String>, N: Into<String>>(schema: S, name: N) -> Self {
+        Self {
+            schema: Some(schema.into()),
+            ..Self::new(name)
+        }
+    }
+
+    /// Set attribute, attributes which are not set are bound as NULL
+    pub fn attribute<N: Into<String>, V: ToValue>(mut self, name: N, value: V) -> Self {
+        self.attributes.push((name.into(), value.to_value()));
+        self
+    }
+
+    /// Get attribute value by name, case insensitive
+    pub fn get_value(&self, name: &str) -> Option<&Value> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    pub fn get<T: FromValue>(&self, name: &str) -> Result<T> {
+        let value = self
+            .get_value(name)
+            .ok_or_else(|| Error::Index(format!("Attribute `{}` not found", name)))?;
+
+        T::from_value(value.clone())
+    }
+}
+
+impl ToValue for Object {
+    fn to_value(&self) -> Value {
+        Value::Object(self.clone())
+    }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Object(self))
+    }
+}
+
+impl FromValue for Object {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Object(o) => Ok(o),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                std::any::type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+/// Owned object descriptor handle
+pub(crate) struct ObjectDesc(pub(crate) dmdb_sys::dhobjdesc);
+
+impl ObjectDesc {
+    /// Describe the object type `schema.name`
+    pub(crate) fn describe(
+        conn: &InternalConnection,
+        schema: Option<&str>,
+        name: &str,
+    ) -> Result<Self> {
+        let schema = schema
+            .map(std::ffi::CString::new)
+            .transpose()
+            .map_err(|e| Error::Parameter(format!("Invalid object schema: {e}")))?;
+        let c_name = std::ffi::CString::new(name)
+            .map_err(|e| Error::Parameter(format!("Invalid object name: {e}")))?;
+
+        let mut desc: dmdb_sys::dhobjdesc = std::ptr::null_mut();
+        unsafe {
+            let rt = dmdb_sys::dpi_desc_obj(
+                conn.hcon,
+                schema
+                    .as_ref()
+                    .map_or(std::ptr::null_mut(), |s| s.as_ptr() as *mut _),
+                c_name.as_ptr() as *mut dmdb_sys::sdbyte,
+                &mut desc,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, conn.hcon, msg => Error::Parameter(format!("Describe object `{}` failed: {}", name, msg)));
+        }
+
+        Ok(Self(desc))
+    }
+}
+
+impl Drop for ObjectDesc {
+    fn drop(&mut self) {
+        unsafe {
+            dmdb_sys::dpi_free_obj_desc(self.0);
+        }
+    }
+}
+
+/// Owned object handle
+pub(crate) struct ObjectHandle(pub(crate) dmdb_sys::dhobj);
+
+impl ObjectHandle {
+    /// Allocate an object and bind it to the descriptor
+    pub(crate) fn alloc(conn: &InternalConnection, desc: dmdb_sys::dhobjdesc) -> Result<Self> {
+        let mut obj: dmdb_sys::dhobj = std::ptr::null_mut();
+        unsafe {
+            let rt = dmdb_sys::dpi_alloc_obj(conn.hcon, &mut obj);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, conn.hcon, msg => Error::Statement(format!("Allocate object failed: {}", msg)));
+        }
+        let obj = Self(obj);
+
+        unsafe {
+            let rt = dmdb_sys::dpi_bind_obj_desc(obj.0, desc);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_OBJECT, obj.0, msg => Error::Statement(format!("Bind object descriptor failed: {}", msg)));
+        }
+
+        Ok(obj)
+    }
+}
+
+impl Drop for ObjectHandle {
+    fn drop(&mut self) {
+        unsafe {
+            dmdb_sys::dpi_free_obj(self.0);
+        }
+    }
+}
+
+/// Object or collection built for a bind parameter, all handles live until the statement is rebound
+pub(crate) struct BoundObject {
+    pub(crate) handle: ObjectHandle,
+    /// Objects and collections set as attributes or elements
+    children: Vec<BoundObject>,
+    /// Descriptor of the top level object, nested descriptors are owned by it
+    _desc: Option<ObjectDesc>,
+    pub(crate) sql_type: dmdb_sys::sdint2,
+}
+
+impl BoundObject {
+    pub(crate) fn build(conn: &InternalConnection, object: &Object) -> Result<Self> {
+        let desc = ObjectDesc::describe(conn, object.schema.as_deref(), &object.name)?;
+        let mut bound = Self::build_with_desc(conn, desc.0, ValueRef::Object(object))?;
+        bound._desc = Some(desc);
+
+        Ok(bound)
+    }
+
+    /// Build the object or collection by the descriptor which is owned by others
+    pub(crate) fn build_with_desc(
+        conn: &InternalConnection,
+        desc: dmdb_sys::dhobjdesc,
+        value: ValueRef,
+    ) -> Result<Self> {
+        let mut bound = Self {
+            handle: ObjectHandle::alloc(conn, desc)?,
+            children: vec![],
+            _desc: None,
+            sql_type: desc_int(desc, 0, dmdb_sys::DSQL_ATTR_OBJ_TYPE)? as _,
+        };
+        let is_collection = matches!(
+            bound.sql_type as u32,
+            dmdb_sys::DSQL_ARRAY | dmdb_sys::DSQL_SARRAY
+        );
+
+        match value {
+            ValueRef::Object(object) if !is_collection => {
+                let fields = fields(desc)?;
+                for (name, _) in &object.attributes {
+                    if !fields.iter().any(|f| f.name.eq_ignore_ascii_case(name)) {
+                        return Err(Error::Parameter(format!(
+                            "Object `{}` has no attribute `{}`",
+                            object.name, name
+                        )));
+                    }
+                }
+
+                for (index, field) in fields.iter().enumerate() {
+                    let value = object.get_value(&field.name).unwrap_or(&Value::Null);
+                    bound.set_attribute(conn, (index + 1) as _, field, value)?;
+                }
+            }
+            ValueRef::Array(values) if is_collection => {
+                // All elements are described by the first field
+                let element = field(desc, 1)?;
+                for (index, value) in values.iter().enumerate() {
+                    bound.set_attribute(conn, (index + 1) as _, &element, value)?;
+                }
+            }
+            v => {
+                return Err(Error::Parameter(format!(
+                    "Value type mismatch, cannot bind `{:?}` to sql type {}",
+                    v, bound.sql_type
+                )))
+            }
+        }
+
+        Ok(bound)
+    }
+
+    fn set_attribute(
+        &mut self,
+        conn: &InternalConnection,
+        nth: dmdb_sys::udint4,
+        field: &FieldInfo,
+        value: &Value,
+    ) -> Result<()> {
+        let obj = self.handle.0;
+        let set = |ctype: u32, buf: *const u8, len: dmdb_sys::slength| -> Result<()> {
+            unsafe {
+                let rt = dmdb_sys::dpi_set_obj_val(
+                    obj,
+                    nth,
+                    ctype as dmdb_sys::udint2,
+                    buf as dmdb_sys::dpointer,
+                    len,
+                );
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_OBJECT, obj, msg => Error::Parameter(format!("Set object attribute `{}` failed: {}", field.name, msg)));
+            }
+
+            Ok(())
+        };
+
+        match value {
+            Value::Null => set(
+                dmdb_sys::DSQL_C_BINARY,
+                std::ptr::null(),
+                dmdb_sys::DSQL_NULL_DATA as _,
+            )?,
+            Value::Integer(i) => set(
+                dmdb_sys::DSQL_C_SBIGINT,
+                i as *const _ as _,
+                size_of_val(i) as _,
+            )?,
+            Value::Float(f) => set(
+                dmdb_sys::DSQL_C_DOUBLE,
+                f as *const _ as _,
+                size_of_val(f) as _,
+            )?,
+            Value::Decimal(s) | Value::Text(s) => {
+                set(dmdb_sys::DSQL_C_NCHAR, s.as_ptr(), s.len() as _)?
+            }
+            Value::Blob(v) => set(dmdb_sys::DSQL_C_BINARY, v.as_ptr(), v.len() as _)?,
+            Value::DateTime(y, m, d, h, i, s, us) => {
+                let ts = dmdb_sys::dpi_timestamp_t {
+                    year: *y as _,
+                    month: *m as _,
+                    day: *d as _,
+                    hour: *h as _,
+                    minute: *i as _,
+                    second: *s as _,
+                    fraction: (*us).wrapping_mul(1000) as _,
+                };
+                set(
+                    dmdb_sys::DSQL_C_TIMESTAMP,
+                    &ts as *const _ as _,
+                    size_of_val(&ts) as _,
+                )?
+            }
+            Value::Date(y, m, d) => {
+                let date = dmdb_sys::dpi_date_t {
+                    year: *y as _,
+                    month: *m as _,
+                    day: *d as _,
+                };
+                set(
+                    dmdb_sys::DSQL_C_DATE,
+                    &date as *const _ as _,
+                    size_of_val(&date) as _,
+                )?
+            }
+            Value::Time(h, i, s) => {
+                let time = dmdb_sys::dpi_time_t {
+                    hour: *h as _,
+                    minute: *i as _,
+                    second: *s as _,
+                };
+                set(
+                    dmdb_sys::DSQL_C_TIME,
+                    &time as *const _ as _,
+                    size_of_val(&time) as _,
+                )?
+            }
+            Value::IntervalYearMonth(neg, y, m) => {
+                let interval = dmdb_sys::dpi_interval_t {
+                    interval_type: dmdb_sys::DPIINTERVAL_DSQL_IS_YEAR_TO_MONTH,
+                    interval_sign: *neg as _,
+                    intval: dmdb_sys::dpi_interval_struct__bindgen_ty_1 {
+                        year_month: dmdb_sys::dpi_year_month_t {
+                            year: *y as _,
+                            month: *m as _,
+                        },
+                    },
+                };
+                set(
+                    dmdb_sys::DSQL_C_INTERVAL_YEAR_TO_MONTH,
+                    &interval as *const _ as _,
+                    size_of_val(&interval) as _,
+                )?
+            }
+            Value::IntervalDaySecond(neg, d, h, i, s, us) => {
+                let interval = dmdb_sys::dpi_interval_t {
+                    interval_type: dmdb_sys::DPIINTERVAL_DSQL_IS_DAY_TO_SECOND,
+                    interval_sign: *neg as _,
+                    intval: dmdb_sys::dpi_interval_struct__bindgen_ty_1 {
+                        day_second: dmdb_sys::dpi_day_second_t {
+                            day: *d as _,
+                            hour: *h as _,
+                            minute: *i as _,
+                            second: *s as _,
+                            fraction: (*us).wrapping_mul(1000) as _,
+                        },
+                    },
+                };
+                set(
+                    dmdb_sys::DSQL_C_INTERVAL_DAY_TO_SECOND,
+                    &interval as *const _ as _,
+                    size_of_val(&interval) as _,
+                )?
+            }
+            Value::DateTimeTz(y, m, d, h, i, s, us, tz) => {
+                let text = datetime::format_timestamp_tz((*y, *m, *d, *h, *i, *s, *us, *tz));
+                set(dmdb_sys::DSQL_C_NCHAR, text.as_ptr(), text.len() as _)?
+            }
+            Value::TimeTz(h, i, s, tz) => {
+                let text = datetime::format_time_tz((*h, *i, *s, *tz));
+                set(dmdb_sys::DSQL_C_NCHAR, text.as_ptr(), text.len() as _)?
+            }
+            Value::Geometry(srid, wkb) => {
+                let geometry = Value::Object(geometry::to_object(*srid, wkb));
+                return self.set_attribute(conn, nth, field, &geometry);
+            }
+            Value::Object(_) | Value::Array(_) => {
+                if field.desc.is_null() {
+                    return Err(Error::Parameter(format!(
+                        "Object attribute `{}` is not an object or collection",
+                        field.name
+                    )));
+                }
+
+                let child = Self::build_with_desc(conn, field.desc, value.into())?;
+                set(
+                    object_ctype(child.sql_type)?,
+                    &child.handle.0 as *const _ as _,
+                    size_of::<dmdb_sys::dhobj>() as _,
+                )?;
+
+                // Save child
+                self.children.push(child);
+            }
+            v => {
+                return Err(Error::Parameter(format!(
+                    "Unsupport object attribute value `{:?}`",
+                    v
+                )))
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the C type of the object or collection sql type
+pub(crate) fn object_ctype(sql_type: dmdb_sys::sdint2) -> Result<u32> {
+    match sql_type as u32 {
+        dmdb_sys::DSQL_CLASS => Ok(dmdb_sys::DSQL_C_CLASS),
+        dmdb_sys::DSQL_RECORD => Ok(dmdb_sys::DSQL_C_RECORD),
+        dmdb_sys::DSQL_ARRAY => Ok(dmdb_sys::DSQL_C_ARRAY),
+        dmdb_sys::DSQL_SARRAY => Ok(dmdb_sys::DSQL_C_SARRAY),
+        _ => Err(Error::Internal(format!(
+            "Unsupport object sql type: {}",
+            sql_type
+        ))),
+    }
+}
+
+/// Get the object descriptor of the result column, it is owned by the statement
+pub(crate) fn column_desc(
+    hstmt: dmdb_sys::dhstmt,
+    index: dmdb_sys::sdint2,
+) -> Result<dmdb_sys::dhobjdesc> {
+    stmt_obj_desc(hstmt, dmdb_sys::DSQL_ATTR_IMP_ROW_DESC, index)
+}
+
+/// Get the object descriptor of the parameter, it is owned by the statement
+pub(crate) fn param_desc(
+    hstmt: dmdb_sys::dhstmt,
+    index: dmdb_sys::sdint2,
+) -> Result<dmdb_sys::dhobjdesc> {
+    stmt_obj_desc(hstmt, dmdb_sys::DSQL_ATTR_IMP_PARAM_DESC, index)
+}
+
+fn stmt_obj_desc(
+    hstmt: dmdb_sys::dhstmt,
+    attr: u32,
+    index: dmdb_sys::sdint2,
+) -> Result<dmdb_sys::dhobjdesc> {
+    let mut hdesc: dmdb_sys::dhdesc = std::ptr::null_mut();
+    let mut desc: dmdb_sys::dhobjdesc = std::ptr::null_mut();
+
+    unsafe {
+        let rt = dmdb_sys::dpi_get_stmt_attr(
+            hstmt,
+            attr as _,
+            &mut hdesc as *mut _ as dmdb_sys::dpointer,
+            0,
+            std::ptr::null_mut(),
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, hstmt, msg => Error::Statement(format!("Get statement descriptor failed: {}", msg)));
+
+        let rt = dmdb_sys::dpi_get_desc_field(
+            hdesc,
+            index,
+            dmdb_sys::DSQL_DESC_OBJ_DESCRIPTOR as _,
+            &mut desc as *mut _ as dmdb_sys::dpointer,
+            0,
+            std::ptr::null_mut(),
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_DESC, hdesc, msg => Error::Statement(format!("Get object descriptor `{}` failed: {}", index, msg)));
+    }
+
+    Ok(desc)
+}
+
+/// Read the object or collection
+pub(crate) fn read_value(
+    conn: &InternalConnection,
+    obj: dmdb_sys::dhobj,
+    desc: dmdb_sys::dhobjdesc,
+) -> Result<Value> {
+    let sql_type = desc_int(desc, 0, dmdb_sys::DSQL_ATTR_OBJ_TYPE)? as u32;
+    if !matches!(sql_type, dmdb_sys::DSQL_ARRAY | dmdb_sys::DSQL_SARRAY) {
+        return Ok(geometry::from_object(read_object(conn, obj, desc)?));
+    }
+
+    let mut count: dmdb_sys::sdint4 = 0;
+    unsafe {
+        let rt = dmdb_sys::dpi_get_obj_attr(
+            obj,
+            0,
+            dmdb_sys::DSQL_ATTR_OBJ_VAL_COUNT as _,
+            &mut count as *mut _ as dmdb_sys::dpointer,
+            size_of_val(&count) as _,
+            std::ptr::null_mut(),
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_OBJECT, obj, msg => Error::Statement(format!("Get collection length failed: {}", msg)));
+    }
+
+    // All elements are described by the first field
+    let element = field(desc, 1)?;
+    let mut values = vec![];
+    for nth in 1..=count.max(0) as dmdb_sys::udint4 {
+        values.push(read_attribute(conn, obj, nth, &element)?);
+    }
+
+    Ok(Value::Array(values))
+}
+
+/// Read all attributes of the object
+fn read_object(
+    conn: &InternalConnection,
+    obj: dmdb_sys::dhobj,
+    desc: dmdb_sys::dhobjdesc,
+) -> Result<Object> {
+    let schema = desc_string(desc, 0, dmdb_sys::DSQL_ATTR_OBJ_SCHAME)?;
+    let name = desc_string(desc, 0, dmdb_sys::DSQL_ATTR_OBJ_NAME)?;
+
+    let mut attributes = vec![];
+    for (index, field) in fields(desc)?.into_iter().enumerate() {
+        let value = read_attribute(conn, obj, (index + 1) as _, &field)?;
+        attributes.push((field.name, value));
+    }
+
+    Ok(Object {
+        schema: Some(schema).filter(|s| !s.is_empty()),
+        name,
+        attributes,
+    })
+}
+
+/// Read the object attribute or collection element
+fn read_attribute(
+    conn: &InternalConnection,
+    obj: dmdb_sys::dhobj,
+    nth: dmdb_sys::udint4,
+    field: &FieldInfo,
+) -> Result<Value> {
+    if field.desc.is_null() {
+        let (ctype, value_type) = sql_value_type(field.sql_type, field.precision, field.scale)?;
+        match object_data(obj, nth, ctype)? {
+            Some(buf) => parse_value(conn, value_type, buf),
+            None => Ok(Value::Null),
+        }
+    } else {
+        let mut child = ObjectHandle::alloc(conn, field.desc)?;
+        let mut val_len: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_get_obj_val(
+                obj,
+                nth,
+                object_ctype(field.sql_type)? as dmdb_sys::udint2,
+                &mut child.0 as *mut _ as dmdb_sys::dpointer,
+                size_of::<dmdb_sys::dhobj>() as dmdb_sys::udint4,
+                &mut val_len,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_OBJECT, obj, msg => Error::Statement(format!("Get object attribute `{}` failed: {}", field.name, msg)));
+        }
+
+        if val_len < 0 {
+            Ok(Value::Null)
+        } else {
+            read_value(conn, child.0, field.desc)
+        }
+    }
+}
+
+/// Get raw data of the object attribute, returns `None` if the value is NULL
+fn object_data(obj: dmdb_sys::dhobj, nth: dmdb_sys::udint4, ctype: u32) -> Result<Option<Vec<u8>>> {
+    let mut buf = vec![0u8; 4096];
+
+    loop {
+        let mut val_len: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_get_obj_val(
+                obj,
+                nth,
+                ctype as dmdb_sys::udint2,
+                buf.as_mut_ptr() as dmdb_sys::dpointer,
+                buf.len() as dmdb_sys::udint4,
+                &mut val_len,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_OBJECT, obj, msg => Error::Statement(format!("Get object attribute `{}` failed: {}", nth, msg)));
+        }
+
+        // Value is null
+        if val_len < 0 {
+            return Ok(None);
+        }
+
+        // Leave room for the terminating zero of text
+        if val_len as usize >= buf.len() {
+            buf.resize(val_len as usize + 1, 0);
+            continue;
+        }
+        buf.truncate(val_len as usize);
+
+        return Ok(Some(buf));
+    }
+}
+
+struct FieldInfo {
+    name: String,
+    sql_type: dmdb_sys::sdint2,
+    precision: dmdb_sys::ulength,
+    scale: dmdb_sys::sdint2,
+    /// Descriptor of object or collection attribute, null for other types
+    desc: dmdb_sys::dhobjdesc,
+}
+
+fn fields(desc: dmdb_sys::dhobjdesc) -> Result<Vec<FieldInfo>> {
+    let count = desc_int(desc, 0, dmdb_sys::DSQL_ATTR_OBJ_FIELD_COUNT)?;
+
+    let mut fields = vec![];
+    for nth in 1..=count.max(0) as dmdb_sys::udint4 {
+        fields.push(field(desc, nth)?);
+    }
+
+    Ok(fields)
+}
+
+fn field(desc: dmdb_sys::dhobjdesc, nth: dmdb_sys::udint4) -> Result<FieldInfo> {
+    let sql_type = desc_int(desc, nth, dmdb_sys::DSQL_ATTR_OBJ_TYPE)? as dmdb_sys::sdint2;
+    let sub_desc = if object_ctype(sql_type).is_ok() {
+        let mut sub: dmdb_sys::dhobjdesc = std::ptr::null_mut();
+        desc_attr(
+            desc,
+            nth,
+            dmdb_sys::DSQL_ATTR_OBJ_DESC,
+            &mut sub as *mut _ as dmdb_sys::dpointer,
+            size_of_val(&sub),
+        )?;
+        sub
+    } else {
+        std::ptr::null_mut()
+    };
+
+    Ok(FieldInfo {
+        name: desc_string(desc, nth, dmdb_sys::DSQL_ATTR_OBJ_NAME)?,
+        sql_type,
+        precision: desc_int(desc, nth, dmdb_sys::DSQL_ATTR_OBJ_PREC)? as _,
+        scale: desc_int(desc, nth, dmdb_sys::DSQL_ATTR_OBJ_SCALE)? as _,
+        desc: sub_desc,
+    })
+}
+
+fn desc_int(desc: dmdb_sys::dhobjdesc, nth: dmdb_sys::udint4, attr: u32) -> Result<i32> {
+    let mut val: dmdb_sys::sdint4 = 0;
+    desc_attr(
+        desc,
+        nth,
+        attr,
+        &mut val as *mut _ as dmdb_sys::dpointer,
+        size_of_val(&val) as _,
+    )?;
+
+    Ok(val)
+}
+
+fn desc_string(desc: dmdb_sys::dhobjdesc, nth: dmdb_sys::udint4, attr: u32) -> Result<String> {
+    let mut buf = [0u8; 256];
+    let len = desc_attr(
+        desc,
+        nth,
+        attr,
+        buf.as_mut_ptr() as dmdb_sys::dpointer,
+        buf.len(),
+    )?;
+    let text = buf.get(..len).unwrap_or(&buf);
+
+    Ok(String::from_utf8_lossy(text)
+        .trim_end_matches('\0')
+        .to_string())
+}
+
+fn desc_attr(
+    desc: dmdb_sys::dhobjdesc,
+    nth: dmdb_sys::udint4,
+    attr: u32,
+    buf: dmdb_sys::dpointer,
+    buf_len: usize,
+) -> Result<usize> {
+    let mut len: dmdb_sys::slength = 0;
+    unsafe {
+        let rt = dmdb_sys::dpi_get_obj_desc_attr(
+            desc,
+            nth,
+            attr as dmdb_sys::udint2,
+            buf,
+            buf_len as dmdb_sys::udint4,
+            &mut len,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_OBJDESC, desc, msg => Error::Statement(format!("Get object descriptor attribute `{}` failed: {}", attr, msg)));
+    }
+
+    Ok(len.max(0) as usize)
+}
@@ -1,114 +1,548 @@
 use std::mem::{size_of, size_of_val};
 
-use crate::{utils::error::error_check, Error, Result, Statement, ToValue, Value};
+use crate::{
+    bfile::BFileLocator,
+    geometry,
+    object::{self, BoundObject},
+    rowid,
+    utils::{datetime, decimal, error::error_check},
+    Error, Lob, Result, Statement, StreamSource, ToValue, Value, ValueRef,
+};
+
+/// Max length of `VARBINARY`, larger binary data is bound as `BLOB`
+const VARBINARY_MAX_LEN: usize = 8188;
+
+/// Max length of `VARCHAR` in bytes, longer text is bound as `CLOB`
+const VARCHAR_MAX_LEN: usize = 8188;
 
 pub trait Params {
     fn bind(&self, stmt: &mut Statement) -> Result<()>;
+
+    /// Send data of the data-at-execution parameter, `index` is 0-based
+    fn put_data(&self, index: usize, _stmt: &mut Statement) -> Result<()> {
+        Err(Error::Parameter(format!(
+            "Parameter `{}` is not a stream",
+            index + 1
+        )))
+    }
+}
+
+/// Parameter to bind, implemented by all [`ToValue`] types and the LOB types which are bound
+/// without a value, i.e. [`Lob`] and [`LobStream`](crate::LobStream)
+pub trait ToParam {
+    /// Value to bind, which fails if the value can't be converted
+    fn param_value(&self) -> Result<Value>;
+
+    /// Borrowed value to bind without copy instead of [`param_value`](Self::param_value)
+    fn param_value_ref(&self) -> Option<ValueRef<'_>> {
+        None
+    }
+
+    /// Data-at-execution source of the parameter
+    fn as_stream(&self) -> Option<&dyn StreamSource> {
+        None
+    }
+
+    /// LOB locator of the parameter
+    fn as_lob(&self) -> Option<&Lob<'_>> {
+        None
+    }
 }
 
-impl Params for [&dyn ToValue; 0] {
+impl<T: ToValue + ?Sized> ToParam for T {
+    fn param_value(&self) -> Result<Value> {
+        self.try_to_value()
+    }
+
+    fn param_value_ref(&self) -> Option<ValueRef<'_>> {
+        self.as_value_ref()
+    }
+}
+
+impl Params for [&dyn ToParam; 0] {
     #[inline]
     fn bind(&self, _: &mut Statement) -> Result<()> {
         Ok(())
     }
 }
 
-impl Params for &[&dyn ToValue] {
+impl Params for &[&dyn ToParam] {
     #[inline]
     fn bind(&self, stmt: &mut Statement) -> Result<()> {
-        stmt.values.clear();
-        stmt.timestampes.clear();
-        stmt.bind_ind_vec.clear();
-
         for (index, param) in self.iter().enumerate() {
-            let value = Box::new(param.to_value());
             let iparam = index as dmdb_sys::udint2 + 1;
-            let ctype = match value.as_ref() {
-                Value::Null => dmdb_sys::DSQL_C_BINARY,
-                Value::Integer(_) => dmdb_sys::DSQL_C_SBIGINT,
-                Value::Float(_) => dmdb_sys::DSQL_C_DOUBLE,
-                Value::Text(_) => dmdb_sys::DSQL_C_NCHAR,
-                Value::Blob(_) => dmdb_sys::DSQL_C_BINARY,
-                Value::DateTime(..) => dmdb_sys::DSQL_C_TIMESTAMP,
-            } as dmdb_sys::sdint2;
-            let dtype = match value.as_ref() {
-                Value::Null => dmdb_sys::DSQL_BLOB,
-                Value::Integer(_) => dmdb_sys::DSQL_BIGINT,
-                Value::Float(_) => dmdb_sys::DSQL_DOUBLE,
-                Value::Text(_) => dmdb_sys::DSQL_CLOB,
-                Value::Blob(_) => dmdb_sys::DSQL_BLOB,
-                Value::DateTime(..) => dmdb_sys::DSQL_TIMESTAMP,
-            } as dmdb_sys::sdint2;
-            let buf = match value.as_ref() {
-                Value::Null => std::ptr::null(),
-                Value::Integer(i) => i as *const _ as *const u8,
-                Value::Float(f) => f as *const _ as *const u8,
-                Value::Text(s) => s.as_ptr(),
-                Value::Blob(v) => v.as_ptr(),
-                Value::DateTime(y, m, d, h, i, s, us) => {
-                    let ts = Box::new(dmdb_sys::dpi_timestamp_t {
-                        year: *y as _,
-                        month: *m as _,
-                        day: *d as _,
-                        hour: *h as _,
-                        minute: *i as _,
-                        second: *s as _,
-                        fraction: (*us).wrapping_mul(1000) as _,
-                    });
-                    let buf = ts.as_ref() as *const _ as *const u8;
-
-                    // Save timestamp
-                    stmt.timestampes.push(ts);
-
-                    buf
-                }
-            };
-            let buf_len = match value.as_ref() {
-                Value::Null => 0,
-                Value::Integer(i) => size_of_val(i),
-                Value::Float(f) => size_of_val(f),
-                Value::Text(s) => s.as_bytes().len(),
-                Value::Blob(v) => v.len(),
-                Value::DateTime(..) => size_of::<dmdb_sys::dpi_timestamp_t>(),
-            };
-
-            // Save ind
-            let ind = if matches!(value.as_ref(), Value::Null) {
-                Box::new(dmdb_sys::DSQL_NULL_DATA as dmdb_sys::slength)
-            } else {
-                Box::new(buf_len as dmdb_sys::slength)
-            };
-            let ind_ptr = (ind.as_ref() as *const dmdb_sys::slength).cast_mut();
-            stmt.bind_ind_vec.push(ind);
+
+            // The stream data is sent by `Statement::execute`
+            if let Some(stream) = param.as_stream() {
+                bind_stream(stmt, iparam, stream.is_clob())?;
+                continue;
+            }
+
+            if let Some(lob) = param.as_lob() {
+                bind_lob(stmt, iparam, lob)?;
+                continue;
+            }
+
+            // Bind the borrowed value without copy, it's alive until the execution finishes
+            if let Some(value) = param.param_value_ref() {
+                bind_value(stmt, iparam, value)?;
+                continue;
+            }
+
+            let value = Box::new(param.param_value()?);
+            bind_value(stmt, iparam, value.as_ref().into())?;
 
             // Save value
             stmt.values.push(value);
+        }
 
-            unsafe {
-                let rt = dmdb_sys::dpi_bind_param(
-                    stmt.hstmt,
-                    iparam,
-                    dmdb_sys::DSQL_PARAM_INPUT as dmdb_sys::sdint2,
-                    ctype,
-                    dtype,
-                    0,
-                    0,
-                    buf as dmdb_sys::dpointer,
-                    buf_len as dmdb_sys::slength,
-                    ind_ptr,
-                );
-                error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, stmt.hstmt, msg => Error::Parameter(msg));
+        Ok(())
+    }
+
+    fn put_data(&self, index: usize, stmt: &mut Statement) -> Result<()> {
+        let stream = self
+            .get(index)
+            .and_then(|param| param.as_stream())
+            .ok_or_else(|| {
+                Error::Parameter(format!("Parameter `{}` is not a stream", index + 1))
+            })?;
+
+        stream.put_data(stmt)
+    }
+}
+
+fn bind_value(stmt: &mut Statement, iparam: dmdb_sys::udint2, value: ValueRef) -> Result<()> {
+    // Objects and collections are bound by handle, the other value types are bound below
+    if let ValueRef::Object(_) | ValueRef::Array(_) | ValueRef::Geometry(..) = value {
+        return bind_object(stmt, iparam, value);
+    }
+
+    let ctype = match value {
+        ValueRef::Null => dmdb_sys::DSQL_C_BINARY,
+        ValueRef::Integer(_) => dmdb_sys::DSQL_C_SBIGINT,
+        ValueRef::Float(_) => dmdb_sys::DSQL_C_DOUBLE,
+        ValueRef::Decimal(_) => dmdb_sys::DSQL_C_NCHAR,
+        ValueRef::Text(_) => dmdb_sys::DSQL_C_NCHAR,
+        ValueRef::Blob(_) => dmdb_sys::DSQL_C_BINARY,
+        ValueRef::DateTime(..) => dmdb_sys::DSQL_C_TIMESTAMP,
+        ValueRef::Date(..) => dmdb_sys::DSQL_C_DATE,
+        ValueRef::Time(..) => dmdb_sys::DSQL_C_TIME,
+        ValueRef::IntervalYearMonth(..) => dmdb_sys::DSQL_C_INTERVAL_YEAR_TO_MONTH,
+        ValueRef::IntervalDaySecond(..) => dmdb_sys::DSQL_C_INTERVAL_DAY_TO_SECOND,
+        ValueRef::DateTimeTz(..) => dmdb_sys::DSQL_C_NCHAR,
+        ValueRef::TimeTz(..) => dmdb_sys::DSQL_C_NCHAR,
+        ValueRef::BFile(..) => dmdb_sys::DSQL_C_BFILE,
+        ValueRef::RowId(_) => dmdb_sys::DSQL_C_BINARY,
+        ValueRef::Object(_) | ValueRef::Array(_) | ValueRef::Geometry(..) => unreachable!(),
+    } as dmdb_sys::sdint2;
+    let dtype = match value {
+        ValueRef::Null => dmdb_sys::DSQL_BLOB,
+        ValueRef::Integer(_) => dmdb_sys::DSQL_BIGINT,
+        ValueRef::Float(_) => dmdb_sys::DSQL_DOUBLE,
+        ValueRef::Decimal(_) => dmdb_sys::DSQL_DEC,
+        ValueRef::Text(s) if s.len() <= VARCHAR_MAX_LEN => dmdb_sys::DSQL_VARCHAR,
+        ValueRef::Text(_) => dmdb_sys::DSQL_CLOB,
+        ValueRef::Blob(v) if v.len() <= VARBINARY_MAX_LEN => dmdb_sys::DSQL_VARBINARY,
+        ValueRef::Blob(_) => dmdb_sys::DSQL_BLOB,
+        ValueRef::DateTime(..) => dmdb_sys::DSQL_TIMESTAMP,
+        ValueRef::Date(..) => dmdb_sys::DSQL_DATE,
+        ValueRef::Time(..) => dmdb_sys::DSQL_TIME,
+        ValueRef::IntervalYearMonth(..) => dmdb_sys::DSQL_INTERVAL_YEAR_TO_MONTH,
+        ValueRef::IntervalDaySecond(..) => dmdb_sys::DSQL_INTERVAL_DAY_TO_SECOND,
+        ValueRef::DateTimeTz(..) => dmdb_sys::DSQL_TIMESTAMP_TZ,
+        ValueRef::TimeTz(..) => dmdb_sys::DSQL_TIME_TZ,
+        ValueRef::BFile(..) => dmdb_sys::DSQL_BFILE,
+        ValueRef::RowId(_) => dmdb_sys::DSQL_ROWID,
+        ValueRef::Object(_) | ValueRef::Array(_) | ValueRef::Geometry(..) => unreachable!(),
+    } as dmdb_sys::sdint2;
+    let (precision, scale) = match value {
+        ValueRef::Decimal(s) => decimal::precision_scale(s),
+        _ => (0, 0),
+    };
+    // Prefer the server described type if the C type of the value matches it, otherwise
+    // the value is bound by its own type and converted by the server
+    let described = stmt
+        .param_infos
+        .get(iparam as usize - 1)
+        .filter(|info| is_compatible(&value, info.raw_sql_type()));
+    let (dtype, precision, scale) = match described {
+        Some(info) => (info.raw_sql_type(), info.raw_precision(), info.scale()),
+        None => (dtype, precision as dmdb_sys::ulength, scale),
+    };
+    let buf = match value {
+        ValueRef::Null => std::ptr::null(),
+        ValueRef::Integer(i) => {
+            let i = Box::new(i);
+            let buf = i.as_ref() as *const _ as *const u8;
+
+            // Save integer
+            stmt.integers.push(i);
+
+            buf
+        }
+        ValueRef::Float(f) => {
+            let f = Box::new(f);
+            let buf = f.as_ref() as *const _ as *const u8;
+
+            // Save float
+            stmt.floats.push(f);
+
+            buf
+        }
+        ValueRef::Decimal(s) => s.as_ptr(),
+        ValueRef::Text(s) => s.as_ptr(),
+        ValueRef::Blob(v) => v.as_ptr(),
+        ValueRef::DateTime(y, m, d, h, i, s, us) => {
+            let ts = Box::new(dmdb_sys::dpi_timestamp_t {
+                year: y as _,
+                month: m as _,
+                day: d as _,
+                hour: h as _,
+                minute: i as _,
+                second: s as _,
+                fraction: us.wrapping_mul(1000) as _,
+            });
+            let buf = ts.as_ref() as *const _ as *const u8;
+
+            // Save timestamp
+            stmt.timestampes.push(ts);
+
+            buf
+        }
+        ValueRef::Date(y, m, d) => {
+            let date = Box::new(dmdb_sys::dpi_date_t {
+                year: y as _,
+                month: m as _,
+                day: d as _,
+            });
+            let buf = date.as_ref() as *const _ as *const u8;
+
+            // Save date
+            stmt.dates.push(date);
+
+            buf
+        }
+        ValueRef::Time(h, i, s) => {
+            let time = Box::new(dmdb_sys::dpi_time_t {
+                hour: h as _,
+                minute: i as _,
+                second: s as _,
+            });
+            let buf = time.as_ref() as *const _ as *const u8;
+
+            // Save time
+            stmt.times.push(time);
+
+            buf
+        }
+        ValueRef::IntervalYearMonth(neg, y, m) => {
+            let interval = Box::new(dmdb_sys::dpi_interval_t {
+                interval_type: dmdb_sys::DPIINTERVAL_DSQL_IS_YEAR_TO_MONTH,
+                interval_sign: neg as _,
+                intval: dmdb_sys::dpi_interval_struct__bindgen_ty_1 {
+                    year_month: dmdb_sys::dpi_year_month_t {
+                        year: y as _,
+                        month: m as _,
+                    },
+                },
+            });
+            let buf = interval.as_ref() as *const _ as *const u8;
+
+            // Save interval
+            stmt.intervals.push(interval);
+
+            buf
+        }
+        ValueRef::IntervalDaySecond(neg, d, h, i, s, us) => {
+            let interval = Box::new(dmdb_sys::dpi_interval_t {
+                interval_type: dmdb_sys::DPIINTERVAL_DSQL_IS_DAY_TO_SECOND,
+                interval_sign: neg as _,
+                intval: dmdb_sys::dpi_interval_struct__bindgen_ty_1 {
+                    day_second: dmdb_sys::dpi_day_second_t {
+                        day: d as _,
+                        hour: h as _,
+                        minute: i as _,
+                        second: s as _,
+                        fraction: us.wrapping_mul(1000) as _,
+                    },
+                },
+            });
+            let buf = interval.as_ref() as *const _ as *const u8;
+
+            // Save interval
+            stmt.intervals.push(interval);
+
+            buf
+        }
+        ValueRef::DateTimeTz(y, m, d, h, i, s, us, tz) => {
+            // DPI has no C struct for time zone types, bind them as text
+            let text = datetime::format_timestamp_tz((y, m, d, h, i, s, us, tz));
+            let buf = text.as_ptr();
+
+            // Save text
+            stmt.texts.push(text);
+
+            buf
+        }
+        ValueRef::TimeTz(h, i, s, tz) => {
+            let text = datetime::format_time_tz((h, i, s, tz));
+            let buf = text.as_ptr();
+
+            // Save text
+            stmt.texts.push(text);
+
+            buf
+        }
+        ValueRef::BFile(dir, file) => {
+            let bfile = Box::new(BFileLocator::construct(stmt.conn, dir, file)?);
+            let buf = &bfile.0 as *const _ as *const u8;
+
+            // Save bfile
+            stmt.bfiles.push(bfile);
+
+            buf
+        }
+        ValueRef::RowId(s) => {
+            let rowid = rowid::char_to_rowid(stmt.conn, s)?;
+            let buf = rowid.as_ptr();
+
+            // Save rowid
+            stmt.rowids.push(rowid);
+
+            buf
+        }
+        ValueRef::Object(_) | ValueRef::Array(_) | ValueRef::Geometry(..) => unreachable!(),
+    };
+    let buf_len = match value {
+        ValueRef::Null => 0,
+        ValueRef::Integer(i) => size_of_val(&i),
+        ValueRef::Float(f) => size_of_val(&f),
+        ValueRef::Decimal(s) => s.len(),
+        ValueRef::Text(s) => s.as_bytes().len(),
+        ValueRef::Blob(v) => v.len(),
+        ValueRef::DateTime(..) => size_of::<dmdb_sys::dpi_timestamp_t>(),
+        ValueRef::Date(..) => size_of::<dmdb_sys::dpi_date_t>(),
+        ValueRef::Time(..) => size_of::<dmdb_sys::dpi_time_t>(),
+        ValueRef::IntervalYearMonth(..) | ValueRef::IntervalDaySecond(..) => {
+            size_of::<dmdb_sys::dpi_interval_t>()
+        }
+        // The formatted text was saved above
+        ValueRef::DateTimeTz(..) | ValueRef::TimeTz(..) => stmt.texts.last().map_or(0, |s| s.len()),
+        ValueRef::BFile(..) => size_of::<dmdb_sys::dhbfile>(),
+        // The raw rowid was saved above
+        ValueRef::RowId(_) => stmt.rowids.last().map_or(0, |v| v.len()),
+        ValueRef::Object(_) | ValueRef::Array(_) | ValueRef::Geometry(..) => unreachable!(),
+    };
+
+    // Save ind
+    let ind = if matches!(value, ValueRef::Null) {
+        Box::new(dmdb_sys::DSQL_NULL_DATA as dmdb_sys::slength)
+    } else {
+        Box::new(buf_len as dmdb_sys::slength)
+    };
+    let ind_ptr = (ind.as_ref() as *const dmdb_sys::slength).cast_mut();
+    stmt.bind_ind_vec.push(ind);
+
+    unsafe {
+        let rt = dmdb_sys::dpi_bind_param(
+            stmt.hstmt,
+            iparam,
+            dmdb_sys::DSQL_PARAM_INPUT as dmdb_sys::sdint2,
+            ctype,
+            dtype,
+            precision,
+            scale,
+            buf as dmdb_sys::dpointer,
+            buf_len as dmdb_sys::slength,
+            ind_ptr,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, stmt.hstmt, msg => Error::Parameter(stmt.with_param_desc_error(msg)));
+    }
+
+    Ok(())
+}
+
+/// Whether the value can be bound with the described SQL type as is
+fn is_compatible(value: &ValueRef, sql_type: dmdb_sys::sdint2) -> bool {
+    let sql_type = sql_type as u32;
+    match value {
+        ValueRef::Null => sql_type != 0,
+        ValueRef::Integer(_) | ValueRef::Float(_) => matches!(
+            sql_type,
+            dmdb_sys::DSQL_BIT
+                | dmdb_sys::DSQL_TINYINT
+                | dmdb_sys::DSQL_SMALLINT
+                | dmdb_sys::DSQL_INT
+                | dmdb_sys::DSQL_BIGINT
+                | dmdb_sys::DSQL_DEC
+                | dmdb_sys::DSQL_FLOAT
+                | dmdb_sys::DSQL_DOUBLE
+        ),
+        ValueRef::Text(_) => matches!(
+            sql_type,
+            dmdb_sys::DSQL_CHAR | dmdb_sys::DSQL_VARCHAR | dmdb_sys::DSQL_CLOB
+        ),
+        ValueRef::Blob(_) => matches!(
+            sql_type,
+            dmdb_sys::DSQL_BINARY | dmdb_sys::DSQL_VARBINARY | dmdb_sys::DSQL_BLOB
+        ),
+        ValueRef::DateTime(..) => sql_type == dmdb_sys::DSQL_TIMESTAMP,
+        ValueRef::Date(..) => sql_type == dmdb_sys::DSQL_DATE,
+        ValueRef::Time(..) => sql_type == dmdb_sys::DSQL_TIME,
+        ValueRef::IntervalYearMonth(..) => matches!(
+            sql_type,
+            dmdb_sys::DSQL_INTERVAL_YEAR
+                | dmdb_sys::DSQL_INTERVAL_MONTH
+                | dmdb_sys::DSQL_INTERVAL_YEAR_TO_MONTH
+        ),
+        ValueRef::IntervalDaySecond(..) => matches!(
+            sql_type,
+            dmdb_sys::DSQL_INTERVAL_DAY
+                | dmdb_sys::DSQL_INTERVAL_HOUR
+                | dmdb_sys::DSQL_INTERVAL_MINUTE
+                | dmdb_sys::DSQL_INTERVAL_SECOND
+                | dmdb_sys::DSQL_INTERVAL_DAY_TO_HOUR
+                | dmdb_sys::DSQL_INTERVAL_DAY_TO_MINUTE
+                | dmdb_sys::DSQL_INTERVAL_DAY_TO_SECOND
+                | dmdb_sys::DSQL_INTERVAL_HOUR_TO_MINUTE
+                | dmdb_sys::DSQL_INTERVAL_HOUR_TO_SECOND
+                | dmdb_sys::DSQL_INTERVAL_MINUTE_TO_SECOND
+        ),
+        ValueRef::TimeTz(..) => sql_type == dmdb_sys::DSQL_TIME_TZ,
+        ValueRef::DateTimeTz(..) => sql_type == dmdb_sys::DSQL_TIMESTAMP_TZ,
+        // Decimal text keeps its own precision and scale, ROWID and BFILE are bound as themselves
+        ValueRef::Decimal(_) | ValueRef::RowId(_) | ValueRef::BFile(..) => false,
+        ValueRef::Object(_) | ValueRef::Array(_) | ValueRef::Geometry(..) => unreachable!(),
+    }
+}
+
+/// Bind a data-at-execution parameter, the parameter number is used as the token of `dpi_param_data`
+fn bind_stream(stmt: &mut Statement, iparam: dmdb_sys::udint2, is_clob: bool) -> Result<()> {
+    let (ctype, dtype) = if is_clob {
+        (dmdb_sys::DSQL_C_NCHAR, dmdb_sys::DSQL_CLOB)
+    } else {
+        (dmdb_sys::DSQL_C_BINARY, dmdb_sys::DSQL_BLOB)
+    };
+
+    // Save ind
+    let ind = Box::new(dmdb_sys::DSQL_DATA_AT_EXEC as dmdb_sys::slength);
+    let ind_ptr = (ind.as_ref() as *const dmdb_sys::slength).cast_mut();
+    stmt.bind_ind_vec.push(ind);
+
+    unsafe {
+        let rt = dmdb_sys::dpi_bind_param(
+            stmt.hstmt,
+            iparam,
+            dmdb_sys::DSQL_PARAM_INPUT as dmdb_sys::sdint2,
+            ctype as dmdb_sys::sdint2,
+            dtype as dmdb_sys::sdint2,
+            0,
+            0,
+            iparam as usize as dmdb_sys::dpointer,
+            0,
+            ind_ptr,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, stmt.hstmt, msg => Error::Parameter(msg));
+    }
+
+    Ok(())
+}
+
+/// Bind a LOB locator, the locator is borrowed by the parameters until execution
+fn bind_lob(stmt: &mut Statement, iparam: dmdb_sys::udint2, lob: &Lob) -> Result<()> {
+    let dtype = if lob.is_clob() {
+        dmdb_sys::DSQL_CLOB
+    } else {
+        dmdb_sys::DSQL_BLOB
+    };
+    let buf_len = size_of::<dmdb_sys::dhloblctr>();
+
+    // Save ind
+    let ind = Box::new(buf_len as dmdb_sys::slength);
+    let ind_ptr = (ind.as_ref() as *const dmdb_sys::slength).cast_mut();
+    stmt.bind_ind_vec.push(ind);
+
+    unsafe {
+        let rt = dmdb_sys::dpi_bind_param(
+            stmt.hstmt,
+            iparam,
+            dmdb_sys::DSQL_PARAM_INPUT as dmdb_sys::sdint2,
+            dmdb_sys::DSQL_C_LOB_HANDLE as dmdb_sys::sdint2,
+            dtype as dmdb_sys::sdint2,
+            0,
+            0,
+            lob.handle() as *const _ as dmdb_sys::dpointer,
+            buf_len as dmdb_sys::slength,
+            ind_ptr,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, stmt.hstmt, msg => Error::Parameter(msg));
+    }
+
+    Ok(())
+}
+
+fn bind_object(stmt: &mut Statement, iparam: dmdb_sys::udint2, value: ValueRef) -> Result<()> {
+    let bound = match value {
+        ValueRef::Object(o) => BoundObject::build(stmt.conn, o)?,
+        ValueRef::Geometry(srid, wkb) => {
+            let object = geometry::to_object(srid, wkb);
+            // Use the described subtype of the parameter, e.g. `ST_POINT` of a point column
+            let described = stmt
+                .param_infos
+                .get(iparam as usize - 1)
+                .is_some_and(|info| {
+                    info.raw_sql_type() == dmdb_sys::DSQL_CLASS as dmdb_sys::sdint2
+                });
+            if described {
+                let desc = object::param_desc(stmt.hstmt, iparam as _)?;
+                BoundObject::build_with_desc(stmt.conn, desc, ValueRef::Object(&object))?
+            } else {
+                BoundObject::build(stmt.conn, &object)?
             }
         }
+        // Collections have no type name, use the descriptor of the parameter
+        _ => {
+            let desc = object::param_desc(stmt.hstmt, iparam as _)?;
+            BoundObject::build_with_desc(stmt.conn, desc, value)?
+        }
+    };
+    let bound = Box::new(bound);
+    let ctype = object::object_ctype(bound.sql_type)?;
+    let dtype = bound.sql_type;
+    let buf = &bound.handle.0 as *const _ as dmdb_sys::dpointer;
+    let buf_len = size_of::<dmdb_sys::dhobj>();
 
-        Ok(())
+    // Save object
+    stmt.objects.push(bound);
+
+    // Save ind
+    let ind = Box::new(buf_len as dmdb_sys::slength);
+    let ind_ptr = (ind.as_ref() as *const dmdb_sys::slength).cast_mut();
+    stmt.bind_ind_vec.push(ind);
+
+    unsafe {
+        let rt = dmdb_sys::dpi_bind_param(
+            stmt.hstmt,
+            iparam,
+            dmdb_sys::DSQL_PARAM_INPUT as dmdb_sys::sdint2,
+            ctype as dmdb_sys::sdint2,
+            dtype,
+            0,
+            0,
+            buf,
+            buf_len as dmdb_sys::slength,
+            ind_ptr,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, stmt.hstmt, msg => Error::Parameter(msg));
     }
+
+    Ok(())
 }
 
 impl Params for &[Value] {
     #[inline]
     fn bind(&self, stmt: &mut Statement) -> Result<()> {
-        let v = self.iter().map(|v| v as &dyn ToValue).collect::<Vec<_>>();
+        let v = self.iter().map(|v| v as &dyn ToParam).collect::<Vec<_>>();
         v.as_slice().bind(stmt)?;
 
         Ok(())
@@ -136,9 +570,9 @@ impl Params for &Vec<Value> {
 #[macro_export]
 macro_rules! params {
     () => {
-        &[] as &[&dyn $crate::ToValue]
+        &[] as &[&dyn $crate::ToParam]
     };
     ($($param:expr),+ $(,)?) => {
-        &[$(&$param as &dyn $crate::ToValue),+] as &[&dyn $crate::ToValue]
+        &[$(&$param as &dyn $crate::ToParam),+] as &[&dyn $crate::ToParam]
     };
 }
@@ -1,13 +1,21 @@
 use std::vec;
 
 use crate::{
-    statement::ColumnInfo, utils::error::error_check, Error, FromValue, Result, Rows, Value,
+    bfile::{BFileLocator, BFileReader},
+    lob::{Lob, LobLocator, LobReader},
+    object::{self, ObjectHandle},
+    rowid,
+    statement::ColumnInfo,
+    utils::{datetime, decimal, error::error_check},
+    Error, FromValue, FromValueRef, InternalConnection, Result, Rows, SqlType, Value, ValueRef,
     ValueType,
 };
 
 pub struct Row<'conn, 'stmt, 'row> {
     rows: &'row Rows<'conn, 'stmt>,
     cache: Vec<Value>,
+    /// LOB locators of streamed columns, `None` for other columns and NULL values
+    lobs: Vec<Option<LobLocator>>,
 }
 
 impl<'conn, 'stmt, 'row> Row<'conn, 'stmt, 'row> {
@@ -15,14 +23,19 @@ impl<'conn, 'stmt, 'row> Row<'conn, 'stmt, 'row> {
         // Genrate value cache
         // XXX: Due to issues with the Dameng Database, the second call to dpi_get_data for the same column retrieves empty data and does not report an error.
         // Therefore, it is necessary to use caching to ensure that dpi_get_data is only called once.
-        let cache = rows
-            .col_infos
-            .iter()
-            .enumerate()
-            .map(|(index, info)| Self::get_value_by_column_info(rows, index, info))
-            .collect::<Result<Vec<_>>>()?;
+        let mut cache = Vec::with_capacity(rows.col_infos.len());
+        let mut lobs = Vec::with_capacity(rows.col_infos.len());
+        for (index, info) in rows.col_infos.iter().enumerate() {
+            if rows.lob_columns.contains(&(index + 1)) {
+                cache.push(Value::Null);
+                lobs.push(Self::fetch_lob_locator(rows, index)?);
+            } else {
+                cache.push(Self::get_value_by_column_info(rows, index, info)?);
+                lobs.push(None);
+            }
+        }
 
-        Ok(Self { rows, cache })
+        Ok(Self { rows, cache, lobs })
     }
 
     pub fn columns(&self) -> &[ColumnInfo] {
@@ -34,18 +47,174 @@ impl<'conn, 'stmt, 'row> Row<'conn, 'stmt, 'row> {
     }
 
     pub fn get_value(&self, index: usize) -> Result<Value> {
+        Ok(self.cached(index)?.clone())
+    }
+
+    /// Get the column borrowing from the row, e.g. `&str` and `&[u8]` without copy
+    pub fn get_ref<'a, T: FromValueRef<'a>>(&'a self, index: usize) -> Result<T> {
+        T::from_value_ref(self.get_value_ref(index)?)
+    }
+
+    pub fn get_value_ref(&self, index: usize) -> Result<ValueRef<'_>> {
+        Ok(self.cached(index)?.into())
+    }
+
+    /// Move the value out of the row without copy, the column reads as NULL afterwards
+    pub fn take(&mut self, index: usize) -> Result<Value> {
+        self.cached(index)?;
+
+        Ok(std::mem::replace(&mut self.cache[index - 1], Value::Null))
+    }
+
+    fn cached(&self, index: usize) -> Result<&Value> {
         if index == 0 {
             return Err(Error::Index("Index must not 0".into()));
         }
 
+        if self.rows.lob_columns.contains(&index) {
+            return Err(Error::Index(format!(
+                "Column `{}` is streamed, use `get_lob` instead",
+                index
+            )));
+        }
+
         // Get value from cache
-        let value = self
-            .cache
+        self.cache
             .get(index - 1)
-            .cloned()
-            .ok_or_else(|| Error::Index(format!("Index `{}` out of range", index)))?;
+            .ok_or_else(|| Error::Index(format!("Index `{}` out of range", index)))
+    }
+
+    /// Get a streaming reader of the LOB column, returns `None` if the value is NULL.
+    ///
+    /// The column must be set by [`Rows::stream_lob`] before fetching rows.
+    pub fn get_lob(&self, index: usize) -> Result<Option<LobReader<'_>>> {
+        let Some((lob, is_clob)) = self.lob_locator(index)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(LobReader::new(lob, is_clob)?))
+    }
+
+    /// Get the LOB locator of the column for partial read and in-place edit,
+    /// returns `None` if the value is NULL.
+    ///
+    /// The column must be set by [`Rows::stream_lob`] before fetching rows, and selected by
+    /// `SELECT ... FOR UPDATE` to be writable.
+    pub fn get_lob_locator(&self, index: usize) -> Result<Option<Lob<'_>>> {
+        let Some((lob, is_clob)) = self.lob_locator(index)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Lob::new(lob, is_clob)))
+    }
+
+    fn lob_locator(&self, index: usize) -> Result<Option<(&LobLocator, bool)>> {
+        if !self.rows.lob_columns.contains(&index) {
+            return Err(Error::Index(format!(
+                "Column `{}` is not streamed, call `Rows::stream_lob` first",
+                index
+            )));
+        }
+
+        let Some(lob) = self.lobs.get(index - 1).and_then(|lob| lob.as_ref()) else {
+            return Ok(None);
+        };
+        let is_clob = self.rows.col_infos[index - 1].sql_type() == SqlType::Clob;
+
+        Ok(Some((lob, is_clob)))
+    }
+
+    fn fetch_lob_locator(
+        rows: &'row Rows<'conn, 'stmt>,
+        index: usize,
+    ) -> Result<Option<LobLocator>> {
+        let mut lob = LobLocator::alloc(rows.stmt.hstmt)?;
+
+        let mut val_len: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_get_data(
+                rows.stmt.hstmt,
+                (index + 1) as dmdb_sys::udint2,
+                dmdb_sys::DSQL_C_LOB_HANDLE as dmdb_sys::sdint2,
+                &mut lob.0 as *mut _ as dmdb_sys::dpointer,
+                0,
+                &mut val_len,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, rows.stmt.hstmt, msg => Error::Statement(format!("Get column lob `{}` failed: {}", index + 1, msg)));
+        }
+
+        // Value is null
+        if val_len < 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(lob))
+    }
+
+    /// Get a reader of the external file referenced by the BFILE column,
+    /// returns `None` if the value is NULL.
+    pub fn get_bfile(&self, index: usize) -> Result<Option<BFileReader<'conn>>> {
+        match self.get_value(index)? {
+            Value::Null => Ok(None),
+            Value::BFile(dir, file) => {
+                Ok(Some(BFileReader::open(self.rows.stmt.conn, &dir, &file)?))
+            }
+            v => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to bfile",
+                v
+            ))),
+        }
+    }
+
+    fn get_bfile_value(rows: &'row Rows<'conn, 'stmt>, index: usize) -> Result<Value> {
+        let mut bfile = BFileLocator::alloc(rows.stmt.conn)?;
+
+        let mut val_len: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_get_data(
+                rows.stmt.hstmt,
+                (index + 1) as dmdb_sys::udint2,
+                dmdb_sys::DSQL_C_BFILE as dmdb_sys::sdint2,
+                &mut bfile.0 as *mut _ as dmdb_sys::dpointer,
+                0,
+                &mut val_len,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, rows.stmt.hstmt, msg => Error::Statement(format!("Get column bfile `{}` failed: {}", index + 1, msg)));
+        }
 
-        Ok(value)
+        // Value is null
+        if val_len < 0 {
+            return Ok(Value::Null);
+        }
+
+        let (dir, file) = bfile.name()?;
+
+        Ok(Value::BFile(dir, file))
+    }
+
+    fn get_object_value(rows: &'row Rows<'conn, 'stmt>, index: usize, ctype: u32) -> Result<Value> {
+        let desc = object::column_desc(rows.stmt.hstmt, (index + 1) as dmdb_sys::sdint2)?;
+        let mut obj = ObjectHandle::alloc(rows.stmt.conn, desc)?;
+
+        let mut val_len: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_get_data(
+                rows.stmt.hstmt,
+                (index + 1) as dmdb_sys::udint2,
+                ctype as dmdb_sys::sdint2,
+                &mut obj.0 as *mut _ as dmdb_sys::dpointer,
+                0,
+                &mut val_len,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, rows.stmt.hstmt, msg => Error::Statement(format!("Get column object `{}` failed: {}", index + 1, msg)));
+        }
+
+        // Value is null
+        if val_len < 0 {
+            return Ok(Value::Null);
+        }
+
+        object::read_value(rows.stmt.conn, obj.0, desc)
     }
 
     fn get_value_by_column_info(
@@ -53,74 +222,32 @@ impl<'conn, 'stmt, 'row> Row<'conn, 'stmt, 'row> {
         index: usize,
         info: &ColumnInfo,
     ) -> Result<Value> {
+        // BFILE is fetched as a locator
+        if info.sql_type() == SqlType::BFile {
+            return Self::get_bfile_value(rows, index);
+        }
+
+        // Objects and collections are fetched as object handles
+        if let Ok(ctype) = object::object_ctype(info.raw_sql_type()) {
+            return Self::get_object_value(rows, index, ctype);
+        }
+
         // Get value buffer info
-        let (ctype, value_type) = match info.sql_type() as u32 {
-            #[rustfmt::skip]
-            dmdb_sys::DSQL_CHAR | dmdb_sys::DSQL_VARCHAR | dmdb_sys::DSQL_CLOB => {
-                (dmdb_sys::DSQL_C_CHAR, ValueType::Text)
-            },
-            #[rustfmt::skip]
-            dmdb_sys::DSQL_BIT | dmdb_sys::DSQL_TINYINT | dmdb_sys::DSQL_SMALLINT | dmdb_sys::DSQL_INT | dmdb_sys::DSQL_BIGINT => {
-                (dmdb_sys::DSQL_C_SBIGINT, ValueType::Integer)
-            },
-            #[rustfmt::skip]
-            dmdb_sys::DSQL_FLOAT | dmdb_sys::DSQL_DOUBLE | dmdb_sys::DSQL_DEC => {
-                (dmdb_sys::DSQL_C_DOUBLE, ValueType::Float)
-            },
-            #[rustfmt::skip]
-            dmdb_sys::DSQL_BLOB => {
-                (dmdb_sys::DSQL_C_BINARY, ValueType::Blob)
-            },
-            dmdb_sys::DSQL_TIMESTAMP => (dmdb_sys::DSQL_C_TIMESTAMP, ValueType::DateTime),
-            _ => {
-                return Err(Error::Internal(format!(
-                    "Unsupport sql type: {}",
-                    info.sql_type()
-                )))
-            }
-        };
+        let (ctype, value_type) =
+            sql_value_type(info.raw_sql_type(), info.raw_size(), info.scale())?;
 
         // Get raw data
-        let Some(buf) = Self::recevie_data(rows, (index + 1) as dmdb_sys::udint2, ctype as dmdb_sys::sdint2)? else {
+        let Some(buf) = Self::recevie_data(
+            rows,
+            (index + 1) as dmdb_sys::udint2,
+            ctype as dmdb_sys::sdint2,
+        )?
+        else {
             // Value is null
-            return Ok(Value::Null)
-        };
-
-        // Parse column data to value
-        let value = match value_type {
-            ValueType::Null => Value::Null,
-            ValueType::Integer => {
-                let n = unsafe { *(buf.as_ptr() as *const i64) };
-                Value::Integer(n)
-            }
-            ValueType::Float => {
-                let n = unsafe { *(buf.as_ptr() as *const f64) };
-                Value::Float(n)
-            }
-            ValueType::Text => {
-                let string = String::from_utf8(buf)
-                    .map_err(|e| Error::FromValue(format!("Parse text value failed: {e}")))?;
-                Value::Text(string)
-            }
-            ValueType::Blob => Value::Blob(buf),
-            ValueType::DateTime => {
-                let ptr = buf.as_ptr() as *const dmdb_sys::dpi_timestamp_t;
-                unsafe {
-                    let ts = &*ptr;
-                    Value::DateTime(
-                        ts.year as _,
-                        ts.month as _,
-                        ts.day as _,
-                        ts.hour as _,
-                        ts.minute as _,
-                        ts.second as _,
-                        ts.fraction.wrapping_div(1000),
-                    )
-                }
-            }
+            return Ok(Value::Null);
         };
 
-        Ok(value)
+        parse_value(rows.stmt.conn, value_type, buf)
     }
 
     fn recevie_data(
@@ -172,3 +299,171 @@ impl<'conn, 'stmt, 'row> Row<'conn, 'stmt, 'row> {
         Ok(Some(out_buf))
     }
 }
+
+/// Get the C type and value type used to fetch the sql type
+pub(crate) fn sql_value_type(
+    sql_type: dmdb_sys::sdint2,
+    precision: dmdb_sys::ulength,
+    scale: dmdb_sys::sdint2,
+) -> Result<(u32, ValueType)> {
+    let res = match sql_type as u32 {
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_CHAR | dmdb_sys::DSQL_VARCHAR | dmdb_sys::DSQL_CLOB => {
+            (dmdb_sys::DSQL_C_CHAR, ValueType::Text)
+        },
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_BIT | dmdb_sys::DSQL_TINYINT | dmdb_sys::DSQL_SMALLINT | dmdb_sys::DSQL_INT | dmdb_sys::DSQL_BIGINT => {
+            (dmdb_sys::DSQL_C_SBIGINT, ValueType::Integer)
+        },
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_FLOAT | dmdb_sys::DSQL_DOUBLE => {
+            (dmdb_sys::DSQL_C_DOUBLE, ValueType::Float)
+        },
+        // Integer numbers that fit i64
+        dmdb_sys::DSQL_DEC if scale == 0 && (1..=18).contains(&precision) => {
+            (dmdb_sys::DSQL_C_SBIGINT, ValueType::Integer)
+        }
+        // Fetch other decimals as text to keep the exact digits
+        dmdb_sys::DSQL_DEC => (dmdb_sys::DSQL_C_CHAR, ValueType::Decimal),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_BINARY | dmdb_sys::DSQL_VARBINARY | dmdb_sys::DSQL_BLOB => {
+            (dmdb_sys::DSQL_C_BINARY, ValueType::Blob)
+        },
+        dmdb_sys::DSQL_TIMESTAMP => (dmdb_sys::DSQL_C_TIMESTAMP, ValueType::DateTime),
+        dmdb_sys::DSQL_DATE => (dmdb_sys::DSQL_C_DATE, ValueType::Date),
+        dmdb_sys::DSQL_TIME => (dmdb_sys::DSQL_C_TIME, ValueType::Time),
+        // DPI has no C struct for time zone types, fetch them as text
+        dmdb_sys::DSQL_TIMESTAMP_TZ => (dmdb_sys::DSQL_C_CHAR, ValueType::DateTimeTz),
+        dmdb_sys::DSQL_TIME_TZ => (dmdb_sys::DSQL_C_CHAR, ValueType::TimeTz),
+        dmdb_sys::DSQL_ROWID => (dmdb_sys::DSQL_C_BINARY, ValueType::RowId),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_YEAR => (dmdb_sys::DSQL_C_INTERVAL_YEAR, ValueType::IntervalYearMonth),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_MONTH => (dmdb_sys::DSQL_C_INTERVAL_MONTH, ValueType::IntervalYearMonth),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_YEAR_TO_MONTH => (dmdb_sys::DSQL_C_INTERVAL_YEAR_TO_MONTH, ValueType::IntervalYearMonth),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_DAY => (dmdb_sys::DSQL_C_INTERVAL_DAY, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_HOUR => (dmdb_sys::DSQL_C_INTERVAL_HOUR, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_MINUTE => (dmdb_sys::DSQL_C_INTERVAL_MINUTE, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_SECOND => (dmdb_sys::DSQL_C_INTERVAL_SECOND, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_DAY_TO_HOUR => (dmdb_sys::DSQL_C_INTERVAL_DAY_TO_HOUR, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_DAY_TO_MINUTE => (dmdb_sys::DSQL_C_INTERVAL_DAY_TO_MINUTE, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_DAY_TO_SECOND => (dmdb_sys::DSQL_C_INTERVAL_DAY_TO_SECOND, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_HOUR_TO_MINUTE => (dmdb_sys::DSQL_C_INTERVAL_HOUR_TO_MINUTE, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_HOUR_TO_SECOND => (dmdb_sys::DSQL_C_INTERVAL_HOUR_TO_SECOND, ValueType::IntervalDaySecond),
+        #[rustfmt::skip]
+        dmdb_sys::DSQL_INTERVAL_MINUTE_TO_SECOND => (dmdb_sys::DSQL_C_INTERVAL_MINUTE_TO_SECOND, ValueType::IntervalDaySecond),
+        _ => return Err(Error::Internal(format!("Unsupport sql type: {}", sql_type))),
+    };
+
+    Ok(res)
+}
+
+/// Parse raw data fetched by [`sql_value_type`] to value
+pub(crate) fn parse_value(
+    conn: &InternalConnection,
+    value_type: ValueType,
+    buf: Vec<u8>,
+) -> Result<Value> {
+    let value = match value_type {
+        ValueType::Null => Value::Null,
+        ValueType::Integer => {
+            let n = unsafe { *(buf.as_ptr() as *const i64) };
+            Value::Integer(n)
+        }
+        ValueType::Float => {
+            let n = unsafe { *(buf.as_ptr() as *const f64) };
+            Value::Float(n)
+        }
+        ValueType::Text => {
+            let string = String::from_utf8(buf)
+                .map_err(|e| Error::FromValue(format!("Parse text value failed: {e}")))?;
+            Value::Text(string)
+        }
+        ValueType::Blob => Value::Blob(buf),
+        ValueType::Decimal => {
+            let string = String::from_utf8(buf)
+                .map_err(|e| Error::FromValue(format!("Parse decimal value failed: {e}")))?;
+            Value::Decimal(decimal::normalize(&string))
+        }
+        ValueType::DateTime => {
+            let ptr = buf.as_ptr() as *const dmdb_sys::dpi_timestamp_t;
+            unsafe {
+                let ts = &*ptr;
+                Value::DateTime(
+                    ts.year as _,
+                    ts.month as _,
+                    ts.day as _,
+                    ts.hour as _,
+                    ts.minute as _,
+                    ts.second as _,
+                    ts.fraction.wrapping_div(1000),
+                )
+            }
+        }
+        ValueType::Date => {
+            let ptr = buf.as_ptr() as *const dmdb_sys::dpi_date_t;
+            unsafe {
+                let date = &*ptr;
+                Value::Date(date.year as _, date.month as _, date.day as _)
+            }
+        }
+        ValueType::Time => {
+            let ptr = buf.as_ptr() as *const dmdb_sys::dpi_time_t;
+            unsafe {
+                let time = &*ptr;
+                Value::Time(time.hour as _, time.minute as _, time.second as _)
+            }
+        }
+        ValueType::IntervalYearMonth => {
+            let ptr = buf.as_ptr() as *const dmdb_sys::dpi_interval_t;
+            unsafe {
+                let interval = &*ptr;
+                let ym = &interval.intval.year_month;
+                Value::IntervalYearMonth(interval.interval_sign == 1, ym.year, ym.month)
+            }
+        }
+        ValueType::IntervalDaySecond => {
+            let ptr = buf.as_ptr() as *const dmdb_sys::dpi_interval_t;
+            unsafe {
+                let interval = &*ptr;
+                let ds = &interval.intval.day_second;
+                Value::IntervalDaySecond(
+                    interval.interval_sign == 1,
+                    ds.day,
+                    ds.hour,
+                    ds.minute,
+                    ds.second,
+                    ds.fraction.wrapping_div(1000),
+                )
+            }
+        }
+        ValueType::DateTimeTz => {
+            let text = String::from_utf8_lossy(&buf);
+            let (y, m, d, h, i, s, us, tz) =
+                datetime::parse_timestamp_tz(&text).ok_or_else(|| {
+                    Error::FromValue(format!("Parse timestamp with time zone `{text}` failed"))
+                })?;
+            Value::DateTimeTz(y, m, d, h, i, s, us, tz)
+        }
+        ValueType::TimeTz => {
+            let text = String::from_utf8_lossy(&buf);
+            let (h, i, s, tz) = datetime::parse_time_tz(&text).ok_or_else(|| {
+                Error::FromValue(format!("Parse time with time zone `{text}` failed"))
+            })?;
+            Value::TimeTz(h, i, s, tz)
+        }
+        ValueType::RowId => Value::RowId(rowid::rowid_to_char(conn, &buf)?),
+    };
+
+    Ok(value)
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use crate::{
+    utils::error::error_check, Error, FromValue, InternalConnection, Result, ToValue, Value,
+    ValueRef,
+};
+
+/// Max length of ROWID in both raw and text form
+const ROWID_BUF_LEN: usize = 64;
+
+/// Physical row address, e.g. `SELECT ROWID, ... FROM t` then `UPDATE t ... WHERE ROWID = ?`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RowId(String);
+
+impl RowId {
+    /// Create from the text form returned by server
+    pub fn new<S: Into<String>>(s: S) -> Self {
+        Self(s.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RowId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl ToValue for RowId {
+    fn to_value(&self) -> Value {
+        Value::RowId(self.0.clone())
+    }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::RowId(&self.0))
+    }
+}
+
+impl FromValue for RowId {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::RowId(s) | Value::Text(s) => Ok(Self(s)),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                std::any::type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+/// Convert raw ROWID to text form
+pub(crate) fn rowid_to_char(conn: &InternalConnection, raw: &[u8]) -> Result<String> {
+    let mut buf = [0u8; ROWID_BUF_LEN];
+    let mut len: dmdb_sys::udint4 = 0;
+
+    unsafe {
+        let rt = dmdb_sys::dpi_rowid_to_char(
+            conn.hcon,
+            raw.as_ptr() as *mut dmdb_sys::sdbyte,
+            raw.len() as dmdb_sys::udint4,
+            buf.as_mut_ptr() as *mut dmdb_sys::sdbyte,
+            buf.len() as dmdb_sys::udint4,
+            &mut len,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, conn.hcon, msg => Error::Statement(format!("Convert rowid to char failed: {}", msg)));
+    }
+
+    let text = buf.get(..len as usize).unwrap_or(&buf);
+
+    Ok(String::from_utf8_lossy(text).to_string())
+}
+
+/// Convert text form of ROWID to raw
+pub(crate) fn char_to_rowid(conn: &InternalConnection, s: &str) -> Result<Vec<u8>> {
+    let mut buf = vec![0u8; ROWID_BUF_LEN];
+    let mut len: dmdb_sys::udint4 = 0;
+
+    unsafe {
+        let rt = dmdb_sys::dpi_char_to_rowid(
+            conn.hcon,
+            s.as_ptr() as *mut dmdb_sys::sdbyte,
+            s.len() as dmdb_sys::udint4,
+            buf.as_mut_ptr() as *mut dmdb_sys::sdbyte,
+            buf.len() as dmdb_sys::udint4,
+            &mut len,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, conn.hcon, msg => Error::Parameter(format!("Convert char `{}` to rowid failed: {}", s, msg)));
+    }
+    buf.truncate(len as usize);
+
+    Ok(buf)
+}
+
+/// Build ROWID from node number, partition table id and real row id
+pub(crate) fn build_rowid(
+    conn: &InternalConnection,
+    epno: i32,
+    partno: i64,
+    real_rowid: u64,
+) -> Result<RowId> {
+    let mut buf = [0u8; ROWID_BUF_LEN];
+    let mut len: dmdb_sys::udint4 = 0;
+
+    unsafe {
+        let rt = dmdb_sys::dpi_build_rowid(
+            conn.hcon,
+            epno as dmdb_sys::sdint4,
+            partno as dmdb_sys::sdint8,
+            real_rowid as dmdb_sys::udint8,
+            buf.as_mut_ptr() as *mut dmdb_sys::sdbyte,
+            buf.len() as dmdb_sys::udint4,
+            &mut len,
+        );
+        error_check!(rt, dmdb_sys::DSQL_HANDLE_DBC, conn.hcon, msg => Error::Statement(format!("Build rowid failed: {}", msg)));
+    }
+
+    let raw = buf.get(..len as usize).unwrap_or(&buf);
+
+    Ok(RowId(rowid_to_char(conn, raw)?))
+}
@@ -1,20 +1,24 @@
-use crate::{utils::error::error_check, ColumnInfo, Error, Result, Row, Statement};
+use std::cell::OnceCell;
+
+use crate::{utils::error::error_check, ColumnInfo, Error, Result, Row, SqlType, Statement};
 
 pub struct Rows<'conn, 'stmt> {
     pub(crate) stmt: &'stmt mut Statement<'conn>,
+    /// Names and types of the columns to fetch the rows
     pub(crate) col_infos: Vec<ColumnInfo>,
+    /// Columns with all attributes, described on the first call of [`Rows::columns`]
+    columns: OnceCell<Vec<ColumnInfo>>,
+    /// 1-based indexes of LOB columns fetched as streams
+    pub(crate) lob_columns: Vec<usize>,
 }
 
 impl<'conn, 'stmt> Rows<'conn, 'stmt> {
     pub(crate) fn new(stmt: &'stmt mut Statement<'conn>) -> Result<Self> {
-        let mut columns = vec![];
-        for i in 0..stmt.get_column_count()? {
-            columns.push(stmt.get_column_info(i + 1)?);
-        }
-
         Ok(Self {
+            col_infos: stmt.column_types()?,
+            columns: OnceCell::new(),
             stmt,
-            col_infos: columns,
+            lob_columns: vec![],
         })
     }
 
@@ -33,6 +37,25 @@ impl<'conn, 'stmt> Rows<'conn, 'stmt> {
     }
 
     pub fn columns(&self) -> &[ColumnInfo] {
-        &self.col_infos
+        self.columns
+            .get_or_init(|| self.stmt.describe_column_attrs(self.col_infos.clone()))
+    }
+
+    /// Fetch the BLOB/CLOB column as a LOB locator instead of loading it into memory,
+    /// use [`Row::get_lob`] or [`Row::get_lob_locator`] to access it.
+    pub fn stream_lob(&mut self, index: usize) -> Result<()> {
+        let info = index
+            .checked_sub(1)
+            .and_then(|i| self.col_infos.get(i))
+            .ok_or_else(|| Error::Index(format!("Index `{}` out of range", index)))?;
+        if !matches!(info.sql_type(), SqlType::Blob | SqlType::Clob) {
+            return Err(Error::Index(format!("Column `{}` is not a LOB", index)));
+        }
+
+        if !self.lob_columns.contains(&index) {
+            self.lob_columns.push(index);
+        }
+
+        Ok(())
     }
 }
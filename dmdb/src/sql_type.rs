@@ -0,0 +1,99 @@
+/// SQL data type of a column or a bind parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlType {
+    Char,
+    Varchar,
+    Bit,
+    TinyInt,
+    SmallInt,
+    Int,
+    BigInt,
+    Decimal,
+    Float,
+    Double,
+    Blob,
+    Date,
+    Time,
+    Timestamp,
+    Binary,
+    Varbinary,
+    Clob,
+    TimeTz,
+    TimestampTz,
+    Class,
+    Record,
+    Array,
+    SArray,
+    RowId,
+    BFile,
+    IntervalYear,
+    IntervalMonth,
+    IntervalDay,
+    IntervalHour,
+    IntervalMinute,
+    IntervalSecond,
+    IntervalYearToMonth,
+    IntervalDayToHour,
+    IntervalDayToMinute,
+    IntervalDayToSecond,
+    IntervalHourToMinute,
+    IntervalHourToSecond,
+    IntervalMinuteToSecond,
+    /// Type code not listed above, e.g. `BOOLEAN` of the newer servers
+    Other(i16),
+}
+
+impl SqlType {
+    pub(crate) fn from_raw(sql_type: dmdb_sys::sdint2) -> Self {
+        match sql_type as u32 {
+            dmdb_sys::DSQL_CHAR => Self::Char,
+            dmdb_sys::DSQL_VARCHAR => Self::Varchar,
+            dmdb_sys::DSQL_BIT => Self::Bit,
+            dmdb_sys::DSQL_TINYINT => Self::TinyInt,
+            dmdb_sys::DSQL_SMALLINT => Self::SmallInt,
+            dmdb_sys::DSQL_INT => Self::Int,
+            dmdb_sys::DSQL_BIGINT => Self::BigInt,
+            dmdb_sys::DSQL_DEC => Self::Decimal,
+            dmdb_sys::DSQL_FLOAT => Self::Float,
+            dmdb_sys::DSQL_DOUBLE => Self::Double,
+            dmdb_sys::DSQL_BLOB => Self::Blob,
+            dmdb_sys::DSQL_DATE => Self::Date,
+            dmdb_sys::DSQL_TIME => Self::Time,
+            dmdb_sys::DSQL_TIMESTAMP => Self::Timestamp,
+            dmdb_sys::DSQL_BINARY => Self::Binary,
+            dmdb_sys::DSQL_VARBINARY => Self::Varbinary,
+            dmdb_sys::DSQL_CLOB => Self::Clob,
+            dmdb_sys::DSQL_TIME_TZ => Self::TimeTz,
+            dmdb_sys::DSQL_TIMESTAMP_TZ => Self::TimestampTz,
+            dmdb_sys::DSQL_CLASS => Self::Class,
+            dmdb_sys::DSQL_RECORD => Self::Record,
+            dmdb_sys::DSQL_ARRAY => Self::Array,
+            dmdb_sys::DSQL_SARRAY => Self::SArray,
+            dmdb_sys::DSQL_ROWID => Self::RowId,
+            dmdb_sys::DSQL_BFILE => Self::BFile,
+            dmdb_sys::DSQL_INTERVAL_YEAR => Self::IntervalYear,
+            dmdb_sys::DSQL_INTERVAL_MONTH => Self::IntervalMonth,
+            dmdb_sys::DSQL_INTERVAL_DAY => Self::IntervalDay,
+            dmdb_sys::DSQL_INTERVAL_HOUR => Self::IntervalHour,
+            dmdb_sys::DSQL_INTERVAL_MINUTE => Self::IntervalMinute,
+            dmdb_sys::DSQL_INTERVAL_SECOND => Self::IntervalSecond,
+            dmdb_sys::DSQL_INTERVAL_YEAR_TO_MONTH => Self::IntervalYearToMonth,
+            dmdb_sys::DSQL_INTERVAL_DAY_TO_HOUR => Self::IntervalDayToHour,
+            dmdb_sys::DSQL_INTERVAL_DAY_TO_MINUTE => Self::IntervalDayToMinute,
+            dmdb_sys::DSQL_INTERVAL_DAY_TO_SECOND => Self::IntervalDayToSecond,
+            dmdb_sys::DSQL_INTERVAL_HOUR_TO_MINUTE => Self::IntervalHourToMinute,
+            dmdb_sys::DSQL_INTERVAL_HOUR_TO_SECOND => Self::IntervalHourToSecond,
+            dmdb_sys::DSQL_INTERVAL_MINUTE_TO_SECOND => Self::IntervalMinuteToSecond,
+            _ => Self::Other(sql_type),
+        }
+    }
+}
+
+/// Nullability of the described column or parameter, `None` if unknown
+pub(crate) fn nullable(nullable: dmdb_sys::sdint2) -> Option<bool> {
+    match nullable as u32 {
+        dmdb_sys::DSQL_NO_NULLS => Some(false),
+        dmdb_sys::DSQL_NULLABLE => Some(true),
+        _ => None,
+    }
+}
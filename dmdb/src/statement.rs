@@ -1,13 +1,21 @@
-use std::mem::size_of_val;
-
 use crate::{
-    utils::error::error_check, Error, InternalConnection, Params, Result, Row, Rows, Value,
+    bfile::BFileLocator, object::BoundObject, sql_type, utils::error::error_check, Error,
+    InternalConnection, Params, Result, Row, Rows, SqlType, Value,
 };
 
-#[derive(Debug)]
+/// Server described result set column, see [`Rows::columns`]
+#[derive(Debug, Clone)]
 pub struct ColumnInfo {
     name: String,
     sql_type: dmdb_sys::sdint2,
+    size: dmdb_sys::ulength,
+    /// `None` until the column attributes are described
+    precision: Option<dmdb_sys::slength>,
+    scale: dmdb_sys::sdint2,
+    nullable: dmdb_sys::sdint2,
+    label: String,
+    table: String,
+    schema: String,
 }
 
 impl ColumnInfo {
@@ -15,58 +23,261 @@ impl ColumnInfo {
         &self.name
     }
 
-    pub(crate) fn sql_type(&self) -> dmdb_sys::sdint2 {
+    pub fn sql_type(&self) -> SqlType {
+        SqlType::from_raw(self.sql_type)
+    }
+
+    /// Column size, i.e. max length of the character and binary types or digits of the numeric types,
+    /// `None` if unknown
+    pub fn size(&self) -> Option<usize> {
+        described_len(self.size as dmdb_sys::slength)
+    }
+
+    /// `None` if unknown
+    pub fn precision(&self) -> Option<usize> {
+        self.precision.and_then(described_len)
+    }
+
+    pub fn scale(&self) -> i16 {
+        self.scale
+    }
+
+    /// Whether the column accepts NULL, `None` if unknown
+    pub fn nullable(&self) -> Option<bool> {
+        sql_type::nullable(self.nullable)
+    }
+
+    /// Title of the column, i.e. the alias given by `AS` or the column name
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Base table of the column, empty for expressions
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    /// Schema of the base table, empty for expressions
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    pub(crate) fn raw_sql_type(&self) -> dmdb_sys::sdint2 {
         self.sql_type
     }
+
+    pub(crate) fn raw_size(&self) -> dmdb_sys::ulength {
+        self.size
+    }
 }
 
+/// Server described bind parameter, see [`Statement::params`]
+#[derive(Debug, Clone)]
+pub struct ParamInfo {
+    sql_type: dmdb_sys::sdint2,
+    precision: dmdb_sys::ulength,
+    scale: dmdb_sys::sdint2,
+    nullable: dmdb_sys::sdint2,
+}
+
+impl ParamInfo {
+    pub fn sql_type(&self) -> SqlType {
+        SqlType::from_raw(self.sql_type)
+    }
+
+    /// Max length of the character and binary types, or precision of the numeric types,
+    /// `None` if unknown
+    pub fn precision(&self) -> Option<usize> {
+        described_len(self.precision as dmdb_sys::slength)
+    }
+
+    pub fn scale(&self) -> i16 {
+        self.scale
+    }
+
+    /// Whether the parameter accepts NULL, `None` if unknown
+    pub fn nullable(&self) -> Option<bool> {
+        sql_type::nullable(self.nullable)
+    }
+
+    pub(crate) fn raw_sql_type(&self) -> dmdb_sys::sdint2 {
+        self.sql_type
+    }
+
+    pub(crate) fn raw_precision(&self) -> dmdb_sys::ulength {
+        self.precision
+    }
+}
+
+// The bound values are boxed, their addresses are passed to DPI and must not change when the
+// vectors grow during binding
+#[allow(clippy::vec_box)]
 pub struct Statement<'conn> {
     pub(crate) hstmt: dmdb_sys::dhstmt,
+    /// Described bind parameters, empty if the statement can't be described
+    pub(crate) param_infos: Vec<ParamInfo>,
+    /// Error of describing the bind parameters, the values are bound by their own types then
+    pub(crate) param_desc_error: Option<Error>,
     /// Temorary save the values for each bind parameter
     pub(crate) values: Vec<Box<Value>>,
+    /// Temorary save the integer values for each bind parameter
+    pub(crate) integers: Vec<Box<i64>>,
+    /// Temorary save the float values for each bind parameter
+    pub(crate) floats: Vec<Box<f64>>,
     /// Temorary save the timestamp values for each bind parameter
     pub(crate) timestampes: Vec<Box<dmdb_sys::dpi_timestamp_t>>,
+    /// Temorary save the date values for each bind parameter
+    pub(crate) dates: Vec<Box<dmdb_sys::dpi_date_t>>,
+    /// Temorary save the time values for each bind parameter
+    pub(crate) times: Vec<Box<dmdb_sys::dpi_time_t>>,
+    /// Temorary save the interval values for each bind parameter
+    pub(crate) intervals: Vec<Box<dmdb_sys::dpi_interval_t>>,
+    /// Temorary save the formatted text values for each bind parameter
+    pub(crate) texts: Vec<String>,
+    /// Temorary save the bfile locators for each bind parameter
+    pub(crate) bfiles: Vec<Box<BFileLocator>>,
+    /// Temorary save the raw rowids for each bind parameter
+    pub(crate) rowids: Vec<Vec<u8>>,
+    /// Temorary save the objects for each bind parameter
+    pub(crate) objects: Vec<Box<BoundObject>>,
     /// Temorary save the buffer size for each value
     pub(crate) bind_ind_vec: Vec<Box<dmdb_sys::slength>>,
-    _conn: &'conn InternalConnection,
+    pub(crate) conn: &'conn InternalConnection,
 }
 
 impl<'conn> Statement<'conn> {
     pub(crate) fn new(hstmt: dmdb_sys::dhstmt, conn: &'conn InternalConnection) -> Self {
         Self {
             hstmt,
+            param_infos: vec![],
+            param_desc_error: None,
             values: vec![],
+            integers: vec![],
+            floats: vec![],
             timestampes: vec![],
+            dates: vec![],
+            times: vec![],
+            intervals: vec![],
+            texts: vec![],
+            bfiles: vec![],
+            rowids: vec![],
+            objects: vec![],
             bind_ind_vec: vec![],
-            _conn: conn,
+            conn,
         }
     }
 
     pub(crate) fn get_column_info(&self, index: usize) -> Result<ColumnInfo> {
-        let mut name: [u8; 64] = [0; 64];
+        let mut name = vec![0u8; 128];
         let mut name_len: dmdb_sys::sdint2 = 0;
         let mut sql_type: dmdb_sys::sdint2 = 0;
-        unsafe {
-            let rt = dmdb_sys::dpi_desc_column(
-                self.hstmt,
-                index as dmdb_sys::sdint2,
-                &mut name as *mut u8 as *mut dmdb_sys::sdbyte,
-                size_of_val(&name) as dmdb_sys::sdint2,
-                &mut name_len,
-                &mut sql_type,
-                0 as *mut dmdb_sys::ulength,
-                0 as *mut dmdb_sys::sdint2,
-                0 as *mut dmdb_sys::sdint2,
-            );
-            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(format!("Get column info `{}` failed: {}", index, msg)));
+        let mut size: dmdb_sys::ulength = 0;
+        let mut scale: dmdb_sys::sdint2 = 0;
+        let mut nullable: dmdb_sys::sdint2 = 0;
+        loop {
+            unsafe {
+                let rt = dmdb_sys::dpi_desc_column(
+                    self.hstmt,
+                    index as dmdb_sys::sdint2,
+                    name.as_mut_ptr() as *mut dmdb_sys::sdbyte,
+                    name.len() as dmdb_sys::sdint2,
+                    &mut name_len,
+                    &mut sql_type,
+                    &mut size,
+                    &mut scale,
+                    &mut nullable,
+                );
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(format!("Get column info `{}` failed: {}", index, msg)));
+            }
+
+            // The name is truncated, retry with a large enough buffer
+            if (name_len as usize) < name.len() {
+                break;
+            }
+            name.resize(name_len as usize + 1, 0);
         }
+        name.truncate(name_len as usize);
 
         Ok(ColumnInfo {
-            name: String::from_utf8_lossy(&name[..name_len as usize]).to_string(),
+            name: String::from_utf8_lossy(&name).to_string(),
             sql_type,
+            size,
+            precision: None,
+            scale,
+            nullable,
+            label: String::new(),
+            table: String::new(),
+            schema: String::new(),
         })
     }
 
+    /// Describe the column attributes which are not needed to fetch the rows. Not all of them
+    /// are supported by every server and column, e.g. base table of an expression, the
+    /// rejected ones are left unknown or empty.
+    pub(crate) fn describe_column_attrs(&self, mut infos: Vec<ColumnInfo>) -> Vec<ColumnInfo> {
+        for (i, info) in infos.iter_mut().enumerate() {
+            let index = i + 1;
+            info.precision = self.col_attr_int(index, dmdb_sys::DSQL_DESC_PRECISION).ok();
+            info.label = self
+                .col_attr_string(index, dmdb_sys::DSQL_DESC_LABEL)
+                .unwrap_or_else(|_| info.name.clone());
+            info.table = self
+                .col_attr_string(index, dmdb_sys::DSQL_DESC_BASE_TABLE_NAME)
+                .unwrap_or_default();
+            info.schema = self
+                .col_attr_string(index, dmdb_sys::DSQL_DESC_SCHEMA_NAME)
+                .unwrap_or_default();
+        }
+
+        infos
+    }
+
+    fn col_attr_int(&self, index: usize, field: u32) -> Result<dmdb_sys::slength> {
+        let mut val: dmdb_sys::slength = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_col_attr(
+                self.hstmt,
+                index as dmdb_sys::udint2,
+                field as dmdb_sys::udint2,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                &mut val,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(format!("Get column attribute `{}` of `{}` failed: {}", field, index, msg)));
+        }
+
+        Ok(val)
+    }
+
+    fn col_attr_string(&self, index: usize, field: u32) -> Result<String> {
+        let mut buf = vec![0u8; 128];
+        let mut len: dmdb_sys::sdint2 = 0;
+        loop {
+            unsafe {
+                let rt = dmdb_sys::dpi_col_attr(
+                    self.hstmt,
+                    index as dmdb_sys::udint2,
+                    field as dmdb_sys::udint2,
+                    buf.as_mut_ptr() as dmdb_sys::dpointer,
+                    buf.len() as dmdb_sys::sdint2,
+                    &mut len,
+                    std::ptr::null_mut(),
+                );
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(format!("Get column attribute `{}` of `{}` failed: {}", field, index, msg)));
+            }
+
+            // The text is truncated, retry with a large enough buffer
+            if (len as usize) < buf.len() {
+                break;
+            }
+            buf.resize(len as usize + 1, 0);
+        }
+        buf.truncate(len.max(0) as usize);
+
+        Ok(String::from_utf8_lossy(&buf).to_string())
+    }
+
     pub(crate) fn get_column_count(&self) -> Result<usize> {
         let mut col_cnt: dmdb_sys::sdint2 = 0;
         unsafe {
@@ -77,6 +288,77 @@ impl<'conn> Statement<'conn> {
         Ok(col_cnt as usize)
     }
 
+    /// Describe the result set columns, available right after prepare
+    pub fn columns(&self) -> Result<Vec<ColumnInfo>> {
+        Ok(self.describe_column_attrs(self.column_types()?))
+    }
+
+    /// Describe the names and types of the result set columns, which are needed to fetch the rows
+    pub(crate) fn column_types(&self) -> Result<Vec<ColumnInfo>> {
+        (1..=self.get_column_count()?)
+            .map(|i| self.get_column_info(i))
+            .collect()
+    }
+
+    pub(crate) fn get_param_info(&self, index: usize) -> Result<ParamInfo> {
+        let mut sql_type: dmdb_sys::sdint2 = 0;
+        let mut precision: dmdb_sys::ulength = 0;
+        let mut scale: dmdb_sys::sdint2 = 0;
+        let mut nullable: dmdb_sys::sdint2 = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_desc_param(
+                self.hstmt,
+                index as dmdb_sys::udint2,
+                &mut sql_type,
+                &mut precision,
+                &mut scale,
+                &mut nullable,
+            );
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(format!("Get param info `{}` failed: {}", index, msg)));
+        }
+
+        Ok(ParamInfo {
+            sql_type,
+            precision,
+            scale,
+            nullable,
+        })
+    }
+
+    /// Number of the bind parameters, available right after prepare
+    pub fn param_count(&self) -> Result<usize> {
+        let mut param_cnt: dmdb_sys::sdint2 = 0;
+        unsafe {
+            let rt = dmdb_sys::dpi_number_params(self.hstmt, &mut param_cnt);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(format!("Get param count error: {}", msg)));
+        }
+
+        Ok(param_cnt as usize)
+    }
+
+    /// Describe the bind parameters by the server, available right after prepare
+    pub fn params(&self) -> Result<Vec<ParamInfo>> {
+        (1..=self.param_count()?)
+            .map(|i| self.get_param_info(i))
+            .collect()
+    }
+
+    /// Describe the bind parameters after prepare, which are used to bind values with the server types
+    pub(crate) fn describe_params(&mut self) {
+        match self.params() {
+            Ok(infos) => self.param_infos = infos,
+            Err(e) => self.param_desc_error = Some(e),
+        }
+    }
+
+    /// Add the describe error to the message of a failed bind or execution, which may be the cause
+    pub(crate) fn with_param_desc_error(&self, msg: String) -> String {
+        match &self.param_desc_error {
+            Some(e) => format!("{} (parameters are bound by the value types: {})", msg, e),
+            None => msg,
+        }
+    }
+
     pub fn query<P: Params>(&mut self, params: P) -> Result<Rows<'conn, '_>> {
         self.execute(params)?;
 
@@ -97,15 +379,66 @@ impl<'conn> Statement<'conn> {
     }
 
     pub fn execute<P: Params>(&mut self, params: P) -> Result<()> {
+        // The parameters of the last execution may be borrowed and freed already
+        self.unbind_params()?;
         params.bind(self)?;
 
         unsafe {
             let rt = dmdb_sys::dpi_exec(self.hstmt);
-            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(msg));
+            if rt == dmdb_sys::DSQL_NEED_DATA as dmdb_sys::DPIRETURN {
+                return self.put_stream_data(&params);
+            }
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(self.with_param_desc_error(msg)));
+        }
+
+        Ok(())
+    }
+
+    /// Unbind all parameters and free the values saved for them
+    fn unbind_params(&mut self) -> Result<()> {
+        unsafe {
+            let rt = dmdb_sys::dpi_unbind_params(self.hstmt);
+            error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(format!("Unbind params failed: {}", msg)));
         }
 
+        self.values.clear();
+        self.integers.clear();
+        self.floats.clear();
+        self.timestampes.clear();
+        self.dates.clear();
+        self.times.clear();
+        self.intervals.clear();
+        self.texts.clear();
+        self.bfiles.clear();
+        self.rowids.clear();
+        self.objects.clear();
+        self.bind_ind_vec.clear();
+
         Ok(())
     }
+
+    /// Send data of all data-at-execution parameters until the execution finishes
+    fn put_stream_data<P: Params>(&mut self, params: &P) -> Result<()> {
+        loop {
+            let mut token: dmdb_sys::dpointer = std::ptr::null_mut();
+            let rt = unsafe { dmdb_sys::dpi_param_data(self.hstmt, &mut token) };
+            if rt != dmdb_sys::DSQL_NEED_DATA as dmdb_sys::DPIRETURN {
+                error_check!(rt, dmdb_sys::DSQL_HANDLE_STMT, self.hstmt, msg => Error::Statement(msg));
+                return Ok(());
+            }
+
+            // The token is the 1-based parameter number
+            let index = (token as usize)
+                .checked_sub(1)
+                .ok_or_else(|| Error::Internal("Invalid data-at-execution token".into()))?;
+            params.put_data(index, self)?;
+        }
+    }
+}
+
+/// Length described by the server, negative ones are unknown
+fn described_len(len: dmdb_sys::slength) -> Option<usize> {
+    usize::try_from(len).ok()
 }
 
 impl Drop for Statement<'_> {
@@ -0,0 +1,138 @@
+use crate::{DateTimeTzTuple, TimeTzTuple};
+
+/// Parse a `TIMESTAMP WITH TIME ZONE` text value, e.g. `2021-03-01 15:38:00.123456 +08:00`
+pub fn parse_timestamp_tz(s: &str) -> Option<DateTimeTzTuple> {
+    let mut parts = s.split_whitespace();
+    let (year, month, day) = parse_date(parts.next()?)?;
+    let (hour, minute, second, us) = parse_time(parts.next()?)?;
+    let offset = parse_offset(parts.next()?)?;
+
+    Some((year, month, day, hour, minute, second, us, offset))
+}
+
+/// Parse a `TIME WITH TIME ZONE` text value, e.g. `15:38:00 +08:00`
+pub fn parse_time_tz(s: &str) -> Option<TimeTzTuple> {
+    let mut parts = s.split_whitespace();
+    let (hour, minute, second, _) = parse_time(parts.next()?)?;
+    let offset = parse_offset(parts.next()?)?;
+
+    Some((hour, minute, second, offset))
+}
+
+pub fn format_timestamp_tz(
+    (year, month, day, hour, minute, second, us, offset): DateTimeTzTuple,
+) -> String {
+    format!(
+        "{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}.{us:06} {}",
+        format_offset(offset)
+    )
+}
+
+pub fn format_time_tz((hour, minute, second, offset): TimeTzTuple) -> String {
+    format!(
+        "{hour:02}:{minute:02}:{second:02} {}",
+        format_offset(offset)
+    )
+}
+
+fn parse_date(s: &str) -> Option<(u16, u8, u8)> {
+    let mut it = s.split('-');
+    let year = it.next()?.parse().ok()?;
+    let month = it.next()?.parse().ok()?;
+    let day = it.next()?.parse().ok()?;
+
+    Some((year, month, day))
+}
+
+fn parse_time(s: &str) -> Option<(u8, u8, u8, u32)> {
+    let (hms, fraction) = s.split_once('.').unwrap_or((s, ""));
+    let mut it = hms.split(':');
+    let hour = it.next()?.parse().ok()?;
+    let minute = it.next()?.parse().ok()?;
+    let second = it.next()?.parse().ok()?;
+
+    // Normalize fraction to microseconds
+    let us = if fraction.is_empty() {
+        0
+    } else {
+        let digits = fraction.get(..fraction.len().min(6))?;
+        digits.parse::<u32>().ok()? * 10u32.pow(6 - digits.len() as u32)
+    };
+
+    Some((hour, minute, second, us))
+}
+
+fn parse_offset(s: &str) -> Option<i16> {
+    let (sign, hm) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => (1, s),
+    };
+    let (h, m) = hm.split_once(':')?;
+    let minutes = h
+        .parse::<i16>()
+        .ok()?
+        .checked_mul(60)?
+        .checked_add(m.parse::<i16>().ok()?)?;
+
+    Some(sign * minutes)
+}
+
+fn format_offset(offset: i16) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let abs = offset.unsigned_abs();
+
+    format!("{sign}{:02}:{:02}", abs / 60, abs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_tz() {
+        assert_eq!(
+            parse_timestamp_tz("2021-03-01 15:38:00.123456 +08:00"),
+            Some((2021, 3, 1, 15, 38, 0, 123456, 480))
+        );
+        assert_eq!(
+            parse_timestamp_tz("2021-03-01 15:38:00 -05:30"),
+            Some((2021, 3, 1, 15, 38, 0, 0, -330))
+        );
+        // Fraction is normalized to microseconds
+        assert_eq!(
+            parse_timestamp_tz("2021-03-01 15:38:00.5 +00:00"),
+            Some((2021, 3, 1, 15, 38, 0, 500000, 0))
+        );
+        assert_eq!(
+            parse_timestamp_tz("2021-03-01 15:38:00.123456789 +00:00"),
+            Some((2021, 3, 1, 15, 38, 0, 123456, 0))
+        );
+        assert_eq!(parse_timestamp_tz("2021-03-01 15:38:00"), None);
+        assert_eq!(parse_timestamp_tz("2021-03-01 15:38:00 +08"), None);
+        // Offset overflowing i16 minutes
+        assert_eq!(parse_timestamp_tz("2021-03-01 15:38:00 +999:00"), None);
+        assert_eq!(parse_timestamp_tz("2021-03-01 15:38:00 +546:08"), None);
+    }
+
+    #[test]
+    fn time_tz() {
+        assert_eq!(parse_time_tz("15:38:00 +08:00"), Some((15, 38, 0, 480)));
+        assert_eq!(parse_time_tz("15:38:00.250 -00:30"), Some((15, 38, 0, -30)));
+        assert_eq!(parse_time_tz("15:38 +08:00"), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        for offset in [0, 480, -330, -30, 14 * 60, -14 * 60] {
+            let ts = (2021, 3, 1, 15, 38, 0, 123, offset);
+            assert_eq!(parse_timestamp_tz(&format_timestamp_tz(ts)), Some(ts));
+
+            let time = (23, 59, 59, offset);
+            assert_eq!(parse_time_tz(&format_time_tz(time)), Some(time));
+        }
+        assert_eq!(format_offset(-330), "-05:30");
+        assert_eq!(format_offset(-30), "-00:30");
+        assert_eq!(format_offset(0), "+00:00");
+    }
+}
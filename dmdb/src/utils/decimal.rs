@@ -0,0 +1,109 @@
+/// Normalize decimal text returned by server, e.g. `-.5` to `-0.5`
+pub fn normalize(s: &str) -> String {
+    let s = s.trim();
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", s),
+    };
+
+    if digits.starts_with('.') {
+        format!("{sign}0{digits}")
+    } else {
+        s.to_string()
+    }
+}
+
+/// Get (precision, scale) of decimal text, e.g. `-0.05` to `(2, 2)` and `1.5E+3` to `(4, 0)`
+pub fn precision_scale(s: &str) -> (usize, i16) {
+    let digits = s.trim().trim_start_matches(['-', '+']);
+    let (mantissa, exp) = match digits.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => (mantissa, exp.parse::<i64>().unwrap_or(0)),
+        None => (digits, 0),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    // Significant digits and the count of them before the decimal point, which is
+    // negative for the leading zeros after the point
+    let all_digits = int_part.len() + frac_part.len();
+    let leading_zeros = int_part
+        .bytes()
+        .chain(frac_part.bytes())
+        .take_while(|&b| b == b'0')
+        .count();
+    let significant = (all_digits - leading_zeros) as i64;
+    let int_digits = int_part.len() as i64 + exp - leading_zeros as i64;
+    let scale = (significant - int_digits).max(0);
+
+    ((int_digits.max(0) + scale).max(1) as usize, scale as i16)
+}
+
+/// Get integer text of decimal if it has no fractional part, e.g. `12.00` to `12`
+pub fn integer_part(s: &str) -> Option<&str> {
+    match s.split_once('.') {
+        Some((int_part, frac_part)) if frac_part.bytes().all(|b| b == b'0') => Some(int_part),
+        Some(_) => None,
+        None => Some(s),
+    }
+}
+
+/// Get integer text of decimal truncated toward zero, e.g. `-12.5` to `-12` and `-.5` to `-0`,
+/// or `None` for exponent notation
+pub fn truncate(s: &str) -> Option<String> {
+    let s = s.trim();
+    if s.contains(['e', 'E']) {
+        return None;
+    }
+    let int_part = s.split_once('.').map_or(s, |(int_part, _)| int_part);
+    match int_part {
+        "" | "-" | "+" => Some(format!("{int_part}0")),
+        _ => Some(int_part.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_leading_point() {
+        assert_eq!(normalize("-.5"), "-0.5");
+        assert_eq!(normalize(".5"), "0.5");
+        assert_eq!(normalize(" 12.5 "), "12.5");
+    }
+
+    #[test]
+    fn precision_scale_of_text() {
+        assert_eq!(precision_scale("123.45"), (5, 2));
+        assert_eq!(precision_scale("-0.5"), (1, 1));
+        assert_eq!(precision_scale("-0.05"), (2, 2));
+        assert_eq!(precision_scale("+1.50"), (3, 2));
+        assert_eq!(precision_scale("007"), (1, 0));
+        assert_eq!(precision_scale("100"), (3, 0));
+        assert_eq!(precision_scale("0"), (1, 0));
+        assert_eq!(precision_scale("0.000"), (3, 3));
+    }
+
+    #[test]
+    fn precision_scale_with_exponent() {
+        assert_eq!(precision_scale("1.5E+3"), (4, 0));
+        assert_eq!(precision_scale("1.5e3"), (4, 0));
+        assert_eq!(precision_scale("1.5e-3"), (4, 4));
+        assert_eq!(precision_scale("-25E-1"), (2, 1));
+    }
+
+    #[test]
+    fn integer_part_of_text() {
+        assert_eq!(integer_part("12.00"), Some("12"));
+        assert_eq!(integer_part("-12"), Some("-12"));
+        assert_eq!(integer_part("12.5"), None);
+    }
+
+    #[test]
+    fn truncate_text() {
+        assert_eq!(truncate("12.99").as_deref(), Some("12"));
+        assert_eq!(truncate("-12.5").as_deref(), Some("-12"));
+        assert_eq!(truncate("-.5").as_deref(), Some("-0"));
+        assert_eq!(truncate(" 7 ").as_deref(), Some("7"));
+        assert_eq!(truncate("1.5E+3"), None);
+    }
+}
@@ -1,2 +1,6 @@
 pub mod cstring;
+pub mod datetime;
+pub mod decimal;
 pub mod error;
+#[cfg(feature = "geo")]
+pub mod wkb;
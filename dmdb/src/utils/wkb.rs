@@ -0,0 +1,398 @@
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+/// EWKB flag of the SRID prefixed geometry
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Encode geometry to little endian 2D WKB
+pub fn encode(geometry: &Geometry<f64>) -> Vec<u8> {
+    let mut buf = vec![];
+    write_geometry(&mut buf, geometry);
+    buf
+}
+
+/// Decode 2D WKB or EWKB, Z and M values are dropped
+pub fn decode(data: &[u8]) -> Option<Geometry<f64>> {
+    let mut reader = Reader { data, le: true };
+    let geometry = reader.geometry()?;
+
+    reader.data.is_empty().then_some(geometry)
+}
+
+fn write_geometry(buf: &mut Vec<u8>, geometry: &Geometry<f64>) {
+    // Byte order
+    buf.push(1);
+
+    match geometry {
+        Geometry::Point(p) => {
+            write_u32(buf, POINT);
+            write_coord(buf, p.0);
+        }
+        Geometry::Line(l) => {
+            write_u32(buf, LINE_STRING);
+            write_u32(buf, 2);
+            write_coord(buf, l.start);
+            write_coord(buf, l.end);
+        }
+        Geometry::LineString(ls) => {
+            write_u32(buf, LINE_STRING);
+            write_line_string(buf, ls);
+        }
+        Geometry::Polygon(p) => {
+            write_u32(buf, POLYGON);
+            write_polygon(buf, p);
+        }
+        Geometry::MultiPoint(mp) => {
+            write_u32(buf, MULTI_POINT);
+            write_u32(buf, mp.0.len() as u32);
+            for p in &mp.0 {
+                write_geometry(buf, &Geometry::Point(*p));
+            }
+        }
+        Geometry::MultiLineString(mls) => {
+            write_u32(buf, MULTI_LINE_STRING);
+            write_u32(buf, mls.0.len() as u32);
+            for ls in &mls.0 {
+                buf.push(1);
+                write_u32(buf, LINE_STRING);
+                write_line_string(buf, ls);
+            }
+        }
+        Geometry::MultiPolygon(mp) => {
+            write_u32(buf, MULTI_POLYGON);
+            write_u32(buf, mp.0.len() as u32);
+            for p in &mp.0 {
+                buf.push(1);
+                write_u32(buf, POLYGON);
+                write_polygon(buf, p);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            write_u32(buf, GEOMETRY_COLLECTION);
+            write_u32(buf, gc.0.len() as u32);
+            for g in &gc.0 {
+                write_geometry(buf, g);
+            }
+        }
+        Geometry::Rect(r) => {
+            write_u32(buf, POLYGON);
+            write_polygon(buf, &r.to_polygon());
+        }
+        Geometry::Triangle(t) => {
+            write_u32(buf, POLYGON);
+            write_polygon(buf, &t.to_polygon());
+        }
+    }
+}
+
+fn write_polygon(buf: &mut Vec<u8>, polygon: &Polygon<f64>) {
+    write_u32(buf, 1 + polygon.interiors().len() as u32);
+    write_line_string(buf, polygon.exterior());
+    for ring in polygon.interiors() {
+        write_line_string(buf, ring);
+    }
+}
+
+fn write_line_string(buf: &mut Vec<u8>, ls: &LineString<f64>) {
+    write_u32(buf, ls.0.len() as u32);
+    for c in &ls.0 {
+        write_coord(buf, *c);
+    }
+}
+
+fn write_coord(buf: &mut Vec<u8>, c: Coord<f64>) {
+    buf.extend(c.x.to_le_bytes());
+    buf.extend(c.y.to_le_bytes());
+}
+
+fn write_u32(buf: &mut Vec<u8>, n: u32) {
+    buf.extend(n.to_le_bytes());
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    le: bool,
+}
+
+impl Reader<'_> {
+    fn geometry(&mut self) -> Option<Geometry<f64>> {
+        self.le = match self.bytes::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return None,
+        };
+
+        let mut ty = self.u32()?;
+        if ty & EWKB_SRID_FLAG != 0 {
+            ty &= !EWKB_SRID_FLAG;
+            self.u32()?;
+        }
+        // EWKB flags of Z and M
+        let mut dims = 2 + ((ty >> 31) & 1) + ((ty >> 30) & 1);
+        ty &= 0x0fff_ffff;
+        // ISO WKB types of Z, M and ZM
+        dims += match ty / 1000 {
+            1 | 2 => 1,
+            3 => 2,
+            _ => 0,
+        };
+        ty %= 1000;
+
+        let geometry = match ty {
+            POINT => Geometry::Point(Point(self.coord(dims)?)),
+            LINE_STRING => Geometry::LineString(self.line_string(dims)?),
+            POLYGON => Geometry::Polygon(self.polygon(dims)?),
+            MULTI_POINT => Geometry::MultiPoint(MultiPoint(
+                self.collection()?
+                    .into_iter()
+                    .map(Point::try_from)
+                    .collect::<Result<_, _>>()
+                    .ok()?,
+            )),
+            MULTI_LINE_STRING => Geometry::MultiLineString(MultiLineString(
+                self.collection()?
+                    .into_iter()
+                    .map(LineString::try_from)
+                    .collect::<Result<_, _>>()
+                    .ok()?,
+            )),
+            MULTI_POLYGON => Geometry::MultiPolygon(MultiPolygon(
+                self.collection()?
+                    .into_iter()
+                    .map(Polygon::try_from)
+                    .collect::<Result<_, _>>()
+                    .ok()?,
+            )),
+            GEOMETRY_COLLECTION => {
+                Geometry::GeometryCollection(GeometryCollection(self.collection()?))
+            }
+            _ => return None,
+        };
+
+        Some(geometry)
+    }
+
+    fn collection(&mut self) -> Option<Vec<Geometry<f64>>> {
+        let count = self.u32()?;
+        (0..count).map(|_| self.geometry()).collect()
+    }
+
+    fn polygon(&mut self, dims: u32) -> Option<Polygon<f64>> {
+        let count = self.u32()?;
+        let mut rings = (0..count)
+            .map(|_| self.line_string(dims))
+            .collect::<Option<Vec<_>>>()?;
+        if rings.is_empty() {
+            return Some(Polygon::new(LineString(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+
+        Some(Polygon::new(exterior, rings))
+    }
+
+    fn line_string(&mut self, dims: u32) -> Option<LineString<f64>> {
+        let count = self.u32()?;
+        (0..count).map(|_| self.coord(dims)).collect()
+    }
+
+    fn coord(&mut self, dims: u32) -> Option<Coord<f64>> {
+        let x = self.f64()?;
+        let y = self.f64()?;
+        for _ in 2..dims {
+            self.f64()?;
+        }
+
+        Some(Coord { x, y })
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.bytes::<4>()?;
+        Some(if self.le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        let bytes = self.bytes::<8>()?;
+        Some(if self.le {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (head, tail) = self.data.split_first_chunk::<N>()?;
+        self.data = tail;
+
+        Some(*head)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geo_types::{line_string, point, polygon};
+
+    use super::*;
+
+    /// WKB writer of either byte order
+    struct Writer {
+        buf: Vec<u8>,
+        le: bool,
+    }
+
+    impl Writer {
+        fn header(&mut self, ty: u32) -> &mut Self {
+            self.buf.push(self.le as u8);
+            self.u32(ty)
+        }
+
+        fn u32(&mut self, n: u32) -> &mut Self {
+            let bytes = if self.le {
+                n.to_le_bytes()
+            } else {
+                n.to_be_bytes()
+            };
+            self.buf.extend(bytes);
+            self
+        }
+
+        fn coords(&mut self, coords: &[(f64, f64)]) -> &mut Self {
+            for &(x, y) in coords {
+                for v in [x, y] {
+                    let bytes = if self.le {
+                        v.to_le_bytes()
+                    } else {
+                        v.to_be_bytes()
+                    };
+                    self.buf.extend(bytes);
+                }
+            }
+            self
+        }
+    }
+
+    fn geometries() -> Vec<Geometry<f64>> {
+        let ls = line_string![(x: 0.0, y: 0.0), (x: 1.5, y: -2.0), (x: 3.0, y: 4.25)];
+        let poly = polygon!(
+            exterior: [(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 0.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0), (x: 1.0, y: 1.0)]],
+        );
+
+        vec![
+            point!(x: 1.0, y: -2.5).into(),
+            ls.clone().into(),
+            poly.clone().into(),
+            MultiPoint(vec![point!(x: 1.0, y: 2.0), point!(x: -3.0, y: 4.0)]).into(),
+            MultiLineString(vec![ls.clone(), ls]).into(),
+            MultiPolygon(vec![poly.clone(), poly]).into(),
+            Geometry::GeometryCollection(GeometryCollection(vec![point!(x: 5.0, y: 6.0).into()])),
+        ]
+    }
+
+    #[test]
+    fn round_trip() {
+        for geometry in geometries() {
+            assert_eq!(decode(&encode(&geometry)), Some(geometry));
+        }
+    }
+
+    #[test]
+    fn decode_both_byte_orders() {
+        let ring = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)];
+        let expected_poly =
+            polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 0.0)];
+
+        for le in [true, false] {
+            let new = || Writer { buf: vec![], le };
+
+            let mut w = new();
+            w.header(POINT).coords(&[(1.0, 2.0)]);
+            assert_eq!(decode(&w.buf), Some(point!(x: 1.0, y: 2.0).into()));
+
+            let mut w = new();
+            w.header(LINE_STRING)
+                .u32(2)
+                .coords(&[(1.0, 2.0), (3.0, 4.0)]);
+            assert_eq!(
+                decode(&w.buf),
+                Some(line_string![(x: 1.0, y: 2.0), (x: 3.0, y: 4.0)].into())
+            );
+
+            let mut w = new();
+            w.header(POLYGON).u32(1).u32(4).coords(&ring);
+            assert_eq!(decode(&w.buf), Some(expected_poly.clone().into()));
+
+            let mut w = new();
+            w.header(MULTI_POINT).u32(2);
+            w.header(POINT).coords(&[(1.0, 2.0)]);
+            w.header(POINT).coords(&[(3.0, 4.0)]);
+            assert_eq!(
+                decode(&w.buf),
+                Some(MultiPoint(vec![point!(x: 1.0, y: 2.0), point!(x: 3.0, y: 4.0)]).into())
+            );
+
+            let mut w = new();
+            w.header(MULTI_LINE_STRING).u32(1);
+            w.header(LINE_STRING)
+                .u32(2)
+                .coords(&[(1.0, 2.0), (3.0, 4.0)]);
+            assert_eq!(
+                decode(&w.buf),
+                Some(
+                    MultiLineString(vec![line_string![(x: 1.0, y: 2.0), (x: 3.0, y: 4.0)]]).into()
+                )
+            );
+
+            let mut w = new();
+            w.header(MULTI_POLYGON).u32(1);
+            w.header(POLYGON).u32(1).u32(4).coords(&ring);
+            assert_eq!(
+                decode(&w.buf),
+                Some(MultiPolygon(vec![expected_poly.clone()]).into())
+            );
+        }
+    }
+
+    #[test]
+    fn decode_srid_and_z() {
+        // EWKB point with SRID 4326
+        let mut w = Writer {
+            buf: vec![],
+            le: false,
+        };
+        w.header(POINT | EWKB_SRID_FLAG)
+            .u32(4326)
+            .coords(&[(1.0, 2.0)]);
+        assert_eq!(decode(&w.buf), Some(point!(x: 1.0, y: 2.0).into()));
+
+        // ISO WKB point Z, the Z value is dropped
+        let mut w = Writer {
+            buf: vec![],
+            le: true,
+        };
+        w.header(1000 + POINT).coords(&[(1.0, 2.0)]);
+        w.buf.extend(3.0f64.to_le_bytes());
+        assert_eq!(decode(&w.buf), Some(point!(x: 1.0, y: 2.0).into()));
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let data = encode(&point!(x: 1.0, y: 2.0).into());
+        assert_eq!(decode(&data[..data.len() - 1]), None);
+        assert_eq!(decode(&[data.as_slice(), &[0]].concat()), None);
+        assert_eq!(decode(&[2, 1, 0, 0, 0]), None);
+    }
+}
@@ -1,18 +1,44 @@
-use std::any::type_name;
+use std::{any::type_name, time::Duration};
 
-use crate::{Error, Result};
+use crate::{utils::decimal, Error, Object, Result};
 
 /// (year, month, day, hour, minute, second, microsecond)
 pub type DateTimeTuple = (u16, u8, u8, u8, u8, u8, u32);
 
+/// (year, month, day)
+pub type DateTuple = (u16, u8, u8);
+
+/// (hour, minute, second)
+pub type TimeTuple = (u8, u8, u8);
+
+/// (year, month, day, hour, minute, second, microsecond, offset minutes)
+pub type DateTimeTzTuple = (u16, u8, u8, u8, u8, u8, u32, i16);
+
+/// (hour, minute, second, offset minutes)
+pub type TimeTzTuple = (u8, u8, u8, i16);
+
+/// (negative, year, month)
+pub type IntervalYearMonthTuple = (bool, u32, u32);
+
+/// (negative, day, hour, minute, second, microsecond)
+pub type IntervalDaySecondTuple = (bool, u32, u32, u32, u32, u32);
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum ValueType {
     Null,
     Integer,
     Float,
+    Decimal,
     Text,
     Blob,
     DateTime,
+    Date,
+    Time,
+    IntervalYearMonth,
+    IntervalDaySecond,
+    DateTimeTz,
+    TimeTz,
+    RowId,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,14 +46,48 @@ pub enum Value {
     Null,
     Integer(i64),
     Float(f64),
+    /// Exact decimal number in text form, e.g. `-123.4500`
+    Decimal(String),
     Text(String),
     Blob(Vec<u8>),
     /// (year, month, day, hour, minute, second, microsecond)
     DateTime(u16, u8, u8, u8, u8, u8, u32),
+    /// (year, month, day)
+    Date(u16, u8, u8),
+    /// (hour, minute, second)
+    Time(u8, u8, u8),
+    /// (negative, year, month)
+    IntervalYearMonth(bool, u32, u32),
+    /// (negative, day, hour, minute, second, microsecond)
+    IntervalDaySecond(bool, u32, u32, u32, u32, u32),
+    /// (year, month, day, hour, minute, second, microsecond, offset minutes)
+    DateTimeTz(u16, u8, u8, u8, u8, u8, u32, i16),
+    /// (hour, minute, second, offset minutes)
+    TimeTz(u8, u8, u8, i16),
+    /// (directory, filename)
+    BFile(String, String),
+    /// Text form of ROWID
+    RowId(String),
+    /// Instance of CLASS or RECORD type
+    Object(Object),
+    /// Elements of ARRAY or SARRAY type
+    Array(Vec<Value>),
+    /// (srid, wkb) of DMGEO geometry
+    Geometry(i32, Vec<u8>),
 }
 
 pub trait ToValue {
     fn to_value(&self) -> Value;
+
+    /// Convert to the value to bind, which fails if the value is out of range of the database types
+    fn try_to_value(&self) -> Result<Value> {
+        Ok(self.to_value())
+    }
+
+    /// Borrowed value of the parameter, which is bound without copy instead of [`to_value`](Self::to_value)
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        None
+    }
 }
 
 macro_rules! impl_to_value_integer {
@@ -53,9 +113,32 @@ impl_to_value_integer!(u16);
 impl_to_value_integer!(i32);
 impl_to_value_integer!(u32);
 impl_to_value_integer!(i64);
-impl_to_value_integer!(u64);
 impl_to_value_integer!(isize);
-impl_to_value_integer!(usize);
+
+macro_rules! impl_to_value_unsigned {
+    ($ty:ident) => {
+        /// Values above `i64::MAX` are converted to decimal text by `to_value`, and rejected by binding
+        impl ToValue for $ty {
+            fn to_value(&self) -> Value {
+                self.try_to_value()
+                    .unwrap_or_else(|_| Value::Decimal(self.to_string()))
+            }
+
+            fn try_to_value(&self) -> Result<Value> {
+                i64::try_from(*self).map(Value::Integer).map_err(|_| {
+                    Error::Parameter(format!(
+                        "Integer `{}` of {} out of range of BIGINT",
+                        self,
+                        type_name::<Self>()
+                    ))
+                })
+            }
+        }
+    };
+}
+
+impl_to_value_unsigned!(u64);
+impl_to_value_unsigned!(usize);
 
 impl ToValue for f32 {
     fn to_value(&self) -> Value {
@@ -73,30 +156,50 @@ impl ToValue for &str {
     fn to_value(&self) -> Value {
         Value::Text(self.to_string())
     }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Text(self))
+    }
 }
 
 impl ToValue for String {
     fn to_value(&self) -> Value {
         Value::Text(self.clone())
     }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Text(self))
+    }
 }
 
 impl ToValue for &[u8] {
     fn to_value(&self) -> Value {
         Value::Blob(self.to_vec())
     }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Blob(self))
+    }
 }
 
 impl ToValue for Vec<u8> {
     fn to_value(&self) -> Value {
         Value::Blob(self.clone())
     }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Blob(self))
+    }
 }
 
 impl ToValue for Value {
     fn to_value(&self) -> Value {
         self.clone()
     }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(self.into())
+    }
 }
 
 impl ToValue for DateTimeTuple {
@@ -105,6 +208,58 @@ impl ToValue for DateTimeTuple {
     }
 }
 
+impl ToValue for DateTuple {
+    fn to_value(&self) -> Value {
+        Value::Date(self.0, self.1, self.2)
+    }
+}
+
+impl ToValue for TimeTuple {
+    fn to_value(&self) -> Value {
+        Value::Time(self.0, self.1, self.2)
+    }
+}
+
+impl ToValue for IntervalYearMonthTuple {
+    fn to_value(&self) -> Value {
+        Value::IntervalYearMonth(self.0, self.1, self.2)
+    }
+}
+
+impl ToValue for IntervalDaySecondTuple {
+    fn to_value(&self) -> Value {
+        Value::IntervalDaySecond(self.0, self.1, self.2, self.3, self.4, self.5)
+    }
+}
+
+impl ToValue for DateTimeTzTuple {
+    fn to_value(&self) -> Value {
+        Value::DateTimeTz(
+            self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7,
+        )
+    }
+}
+
+impl ToValue for TimeTzTuple {
+    fn to_value(&self) -> Value {
+        Value::TimeTz(self.0, self.1, self.2, self.3)
+    }
+}
+
+impl ToValue for Duration {
+    fn to_value(&self) -> Value {
+        let secs = self.as_secs();
+        Value::IntervalDaySecond(
+            false,
+            (secs / 86400).min(u32::MAX as u64) as u32,
+            (secs % 86400 / 3600) as u32,
+            (secs % 3600 / 60) as u32,
+            (secs % 60) as u32,
+            self.subsec_micros(),
+        )
+    }
+}
+
 impl<T: ToValue> ToValue for Option<T> {
     fn to_value(&self) -> Value {
         match self {
@@ -112,6 +267,86 @@ impl<T: ToValue> ToValue for Option<T> {
             None => Value::Null,
         }
     }
+
+    fn try_to_value(&self) -> Result<Value> {
+        match self {
+            Some(v) => v.try_to_value(),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        match self {
+            Some(v) => v.as_value_ref(),
+            None => Some(ValueRef::Null),
+        }
+    }
+}
+
+/// `Vec<T>` is implemented for each element type instead of any `T: ToValue`, because
+/// `Vec<u8>` is binary data, not a collection. Other element types can be bound as
+/// `Vec<Option<T>>` or `Vec<Value>`.
+macro_rules! impl_to_value_array {
+    ($ty:ty) => {
+        impl ToValue for Vec<$ty> {
+            fn to_value(&self) -> Value {
+                Value::Array(self.iter().map(|v| v.to_value()).collect())
+            }
+
+            fn try_to_value(&self) -> Result<Value> {
+                Ok(Value::Array(
+                    self.iter()
+                        .map(|v| v.try_to_value())
+                        .collect::<Result<_>>()?,
+                ))
+            }
+        }
+    };
+}
+
+// Used by the impls for types of optional crates
+#[allow(unused_imports)]
+pub(crate) use impl_to_value_array;
+
+// `Vec<u8>` is binary data, not a collection
+impl_to_value_array!(bool);
+impl_to_value_array!(i8);
+impl_to_value_array!(i16);
+impl_to_value_array!(u16);
+impl_to_value_array!(i32);
+impl_to_value_array!(u32);
+impl_to_value_array!(i64);
+impl_to_value_array!(u64);
+impl_to_value_array!(isize);
+impl_to_value_array!(usize);
+impl_to_value_array!(f32);
+impl_to_value_array!(f64);
+impl_to_value_array!(&str);
+impl_to_value_array!(String);
+impl_to_value_array!(Vec<u8>);
+impl_to_value_array!(Value);
+impl_to_value_array!(Object);
+impl_to_value_array!(DateTimeTuple);
+impl_to_value_array!(DateTuple);
+impl_to_value_array!(TimeTuple);
+impl_to_value_array!(DateTimeTzTuple);
+impl_to_value_array!(TimeTzTuple);
+impl_to_value_array!(IntervalYearMonthTuple);
+impl_to_value_array!(IntervalDaySecondTuple);
+impl_to_value_array!(Duration);
+
+impl<T: ToValue> ToValue for Vec<Option<T>> {
+    fn to_value(&self) -> Value {
+        Value::Array(self.iter().map(|v| v.to_value()).collect())
+    }
+
+    fn try_to_value(&self) -> Result<Value> {
+        Ok(Value::Array(
+            self.iter()
+                .map(|v| v.try_to_value())
+                .collect::<Result<_>>()?,
+        ))
+    }
 }
 
 pub trait FromValue: Sized {
@@ -136,8 +371,36 @@ macro_rules! impl_from_value_integer {
         impl FromValue for $ty {
             fn from_value(v: Value) -> Result<Self> {
                 match v {
-                    Value::Integer(i) => Ok(i as $ty),
-                    Value::Float(f) => Ok(f as $ty),
+                    Value::Integer(i) => $ty::try_from(i).map_err(|_| {
+                        Error::FromValue(format!(
+                            "Integer `{}` out of range of {}",
+                            i,
+                            type_name::<Self>()
+                        ))
+                    }),
+                    // `MIN` and `MAX + 1` are powers of two (or zero) and so exact in f64,
+                    // `MAX as f64` of the 64-bit types already rounds up to `MAX + 1`
+                    Value::Float(f)
+                        if f.fract() == 0.0
+                            && f >= $ty::MIN as f64
+                            && f < $ty::MAX as f64 + 1.0 =>
+                    {
+                        Ok(f as $ty)
+                    }
+                    Value::Float(f) => Err(Error::FromValue(format!(
+                        "Float `{}` cannot convert to {} without loss",
+                        f,
+                        type_name::<Self>()
+                    ))),
+                    Value::Decimal(ref s) => decimal::integer_part(s)
+                        .and_then(|s| s.parse::<$ty>().ok())
+                        .ok_or_else(|| {
+                            Error::FromValue(format!(
+                                "Decimal `{}` cannot convert to {} without loss",
+                                s,
+                                type_name::<Self>()
+                            ))
+                        }),
                     _ => Err(Error::FromValue(format!(
                         "Value type mismatch, cannot convert `{:?}` to {}",
                         v,
@@ -160,11 +423,40 @@ impl_from_value_integer!(u64);
 impl_from_value_integer!(isize);
 impl_from_value_integer!(usize);
 
+/// Error unless the integer is exact in the float type
+macro_rules! integer_to_float {
+    ($i:expr, $ty:ident) => {{
+        let i = $i;
+        let f = i as $ty;
+        // `i64::MAX as f64` rounds up to 2^63 and would saturate back to `i64::MAX`
+        if f as i128 == i as i128 {
+            Ok(f)
+        } else {
+            Err(Error::FromValue(format!(
+                "Integer `{}` cannot convert to {} without loss",
+                i,
+                type_name::<$ty>()
+            )))
+        }
+    }};
+}
+
 impl FromValue for f32 {
     fn from_value(v: Value) -> Result<Self> {
         match v {
-            Value::Integer(i) => Ok(i as f32),
+            Value::Integer(i) => integer_to_float!(i, f32),
+            Value::Float(f) if f.is_finite() && (f as f32).is_infinite() => Err(Error::FromValue(
+                format!("Float `{}` out of range of {}", f, type_name::<Self>()),
+            )),
             Value::Float(f) => Ok(f as f32),
+            Value::Decimal(ref s) => s.parse().map_err(|e| {
+                Error::FromValue(format!(
+                    "Decimal `{}` cannot convert to {}: {}",
+                    s,
+                    type_name::<Self>(),
+                    e
+                ))
+            }),
             _ => Err(Error::FromValue(format!(
                 "Value type mismatch, cannot convert `{:?}` to {}",
                 v,
@@ -177,8 +469,16 @@ impl FromValue for f32 {
 impl FromValue for f64 {
     fn from_value(v: Value) -> Result<Self> {
         match v {
-            Value::Integer(i) => Ok(i as f64),
+            Value::Integer(i) => integer_to_float!(i, f64),
             Value::Float(f) => Ok(f),
+            Value::Decimal(ref s) => s.parse().map_err(|e| {
+                Error::FromValue(format!(
+                    "Decimal `{}` cannot convert to {}: {}",
+                    s,
+                    type_name::<Self>(),
+                    e
+                ))
+            }),
             _ => Err(Error::FromValue(format!(
                 "Value type mismatch, cannot convert `{:?}` to {}",
                 v,
@@ -188,10 +488,74 @@ impl FromValue for f64 {
     }
 }
 
+/// Numeric value converted with `as` casts, e.g. read `3.9` as `Lossy<i32>` gives `3`,
+/// out of range integer is wrapped and out of range float is saturated, decimal is
+/// truncated and then handled like an integer, or like a float beyond `i128`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Lossy<T>(pub T);
+
+impl<T> Lossy<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! impl_from_value_lossy {
+    ($ty:ident) => {
+        // Parsing as f64 would round the integers beyond 53 bits
+        impl_from_value_lossy!($ty, |s: &str| {
+            match decimal::truncate(s).and_then(|s| s.parse::<i128>().ok()) {
+                Some(i) => Ok(i as $ty),
+                None => s.parse::<f64>().map(|f| f as $ty),
+            }
+        });
+    };
+    (float $ty:ident) => {
+        impl_from_value_lossy!($ty, |s: &str| s.parse::<f64>().map(|f| f as $ty));
+    };
+    ($ty:ident, $decimal:expr) => {
+        impl FromValue for Lossy<$ty> {
+            fn from_value(v: Value) -> Result<Self> {
+                match v {
+                    Value::Integer(i) => Ok(Lossy(i as $ty)),
+                    Value::Float(f) => Ok(Lossy(f as $ty)),
+                    Value::Decimal(ref s) => ($decimal)(s.trim()).map(Lossy).map_err(|e| {
+                        Error::FromValue(format!(
+                            "Decimal `{}` cannot convert to {}: {}",
+                            s,
+                            type_name::<Self>(),
+                            e
+                        ))
+                    }),
+                    _ => Err(Error::FromValue(format!(
+                        "Value type mismatch, cannot convert `{:?}` to {}",
+                        v,
+                        type_name::<Self>()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_from_value_lossy!(i8);
+impl_from_value_lossy!(u8);
+impl_from_value_lossy!(i16);
+impl_from_value_lossy!(u16);
+impl_from_value_lossy!(i32);
+impl_from_value_lossy!(u32);
+impl_from_value_lossy!(i64);
+impl_from_value_lossy!(u64);
+impl_from_value_lossy!(isize);
+impl_from_value_lossy!(usize);
+impl_from_value_lossy!(float f32);
+impl_from_value_lossy!(float f64);
+
 impl FromValue for String {
     fn from_value(v: Value) -> Result<Self> {
         match v {
             Value::Text(s) => Ok(s),
+            Value::Decimal(s) => Ok(s),
             _ => Err(Error::FromValue(format!(
                 "Value type mismatch, cannot convert `{:?}` to {}",
                 v,
@@ -221,6 +585,184 @@ impl FromValue for DateTimeTuple {
             Value::DateTime(year, month, day, hour, minute, second, nanosecond) => {
                 Ok((year, month, day, hour, minute, second, nanosecond))
             }
+            Value::Date(year, month, day) => Ok((year, month, day, 0, 0, 0, 0)),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl FromValue for DateTuple {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Date(year, month, day) => Ok((year, month, day)),
+            Value::DateTime(year, month, day, ..) => Ok((year, month, day)),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl FromValue for TimeTuple {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Time(hour, minute, second) => Ok((hour, minute, second)),
+            Value::DateTime(_, _, _, hour, minute, second, _) => Ok((hour, minute, second)),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl FromValue for IntervalYearMonthTuple {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::IntervalYearMonth(negative, year, month) => Ok((negative, year, month)),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl FromValue for IntervalDaySecondTuple {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::IntervalDaySecond(negative, day, hour, minute, second, microsecond) => {
+                Ok((negative, day, hour, minute, second, microsecond))
+            }
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl FromValue for DateTimeTzTuple {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::DateTimeTz(year, month, day, hour, minute, second, microsecond, offset) => {
+                Ok((year, month, day, hour, minute, second, microsecond, offset))
+            }
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl FromValue for TimeTzTuple {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::TimeTz(hour, minute, second, offset) => Ok((hour, minute, second, offset)),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl FromValue for Duration {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::IntervalDaySecond(false, day, hour, minute, second, microsecond) => {
+                let secs = day as u64 * 86400 + hour as u64 * 3600 + minute as u64 * 60;
+                Ok(Duration::from_secs(secs + second as u64)
+                    + Duration::from_micros(microsecond as u64))
+            }
+            Value::IntervalDaySecond(true, ..) => Err(Error::FromValue(format!(
+                "Negative interval `{:?}` cannot convert to {}",
+                v,
+                type_name::<Self>()
+            ))),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+macro_rules! impl_from_value_array {
+    ($ty:ty) => {
+        impl FromValue for Vec<$ty> {
+            fn from_value(v: Value) -> Result<Self> {
+                match v {
+                    Value::Array(values) => values.into_iter().map(<$ty>::from_value).collect(),
+                    _ => Err(Error::FromValue(format!(
+                        "Value type mismatch, cannot convert `{:?}` to {}",
+                        v,
+                        type_name::<Self>()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+// Used by the impls for types of optional crates
+#[allow(unused_imports)]
+pub(crate) use impl_from_value_array;
+
+impl_from_value_array!(bool);
+impl_from_value_array!(i8);
+impl_from_value_array!(i16);
+impl_from_value_array!(u16);
+impl_from_value_array!(i32);
+impl_from_value_array!(u32);
+impl_from_value_array!(i64);
+impl_from_value_array!(u64);
+impl_from_value_array!(isize);
+impl_from_value_array!(usize);
+impl_from_value_array!(f32);
+impl_from_value_array!(f64);
+impl_from_value_array!(String);
+impl_from_value_array!(Vec<u8>);
+impl_from_value_array!(Object);
+impl_from_value_array!(DateTimeTuple);
+impl_from_value_array!(DateTuple);
+impl_from_value_array!(TimeTuple);
+impl_from_value_array!(DateTimeTzTuple);
+impl_from_value_array!(TimeTzTuple);
+impl_from_value_array!(IntervalYearMonthTuple);
+impl_from_value_array!(IntervalDaySecondTuple);
+impl_from_value_array!(Duration);
+
+impl FromValue for Vec<Value> {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Array(values) => Ok(values),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<Option<T>> {
+    fn from_value(v: Value) -> Result<Self> {
+        match v {
+            Value::Array(values) => values.into_iter().map(Option::<T>::from_value).collect(),
             _ => Err(Error::FromValue(format!(
                 "Value type mismatch, cannot convert `{:?}` to {}",
                 v,
@@ -239,3 +781,298 @@ impl<T: FromValue> FromValue for Option<T> {
         }
     }
 }
+
+/// Borrowed view of a [`Value`], see [`Row::get_value_ref`](crate::Row::get_value_ref)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueRef<'a> {
+    Null,
+    Integer(i64),
+    Float(f64),
+    /// Exact decimal number in text form, e.g. `-123.4500`
+    Decimal(&'a str),
+    Text(&'a str),
+    Blob(&'a [u8]),
+    /// (year, month, day, hour, minute, second, microsecond)
+    DateTime(u16, u8, u8, u8, u8, u8, u32),
+    /// (year, month, day)
+    Date(u16, u8, u8),
+    /// (hour, minute, second)
+    Time(u8, u8, u8),
+    /// (negative, year, month)
+    IntervalYearMonth(bool, u32, u32),
+    /// (negative, day, hour, minute, second, microsecond)
+    IntervalDaySecond(bool, u32, u32, u32, u32, u32),
+    /// (year, month, day, hour, minute, second, microsecond, offset minutes)
+    DateTimeTz(u16, u8, u8, u8, u8, u8, u32, i16),
+    /// (hour, minute, second, offset minutes)
+    TimeTz(u8, u8, u8, i16),
+    /// (directory, filename)
+    BFile(&'a str, &'a str),
+    /// Text form of ROWID
+    RowId(&'a str),
+    /// Instance of CLASS or RECORD type
+    Object(&'a Object),
+    /// Elements of ARRAY or SARRAY type
+    Array(&'a [Value]),
+    /// (srid, wkb) of DMGEO geometry
+    Geometry(i32, &'a [u8]),
+}
+
+impl<'a> From<&'a Value> for ValueRef<'a> {
+    fn from(v: &'a Value) -> Self {
+        match *v {
+            Value::Null => ValueRef::Null,
+            Value::Integer(i) => ValueRef::Integer(i),
+            Value::Float(f) => ValueRef::Float(f),
+            Value::Decimal(ref s) => ValueRef::Decimal(s),
+            Value::Text(ref s) => ValueRef::Text(s),
+            Value::Blob(ref b) => ValueRef::Blob(b),
+            Value::DateTime(y, m, d, h, mi, s, us) => ValueRef::DateTime(y, m, d, h, mi, s, us),
+            Value::Date(y, m, d) => ValueRef::Date(y, m, d),
+            Value::Time(h, mi, s) => ValueRef::Time(h, mi, s),
+            Value::IntervalYearMonth(neg, y, m) => ValueRef::IntervalYearMonth(neg, y, m),
+            Value::IntervalDaySecond(neg, d, h, mi, s, us) => {
+                ValueRef::IntervalDaySecond(neg, d, h, mi, s, us)
+            }
+            Value::DateTimeTz(y, m, d, h, mi, s, us, tz) => {
+                ValueRef::DateTimeTz(y, m, d, h, mi, s, us, tz)
+            }
+            Value::TimeTz(h, mi, s, tz) => ValueRef::TimeTz(h, mi, s, tz),
+            Value::BFile(ref dir, ref file) => ValueRef::BFile(dir, file),
+            Value::RowId(ref s) => ValueRef::RowId(s),
+            Value::Object(ref o) => ValueRef::Object(o),
+            Value::Array(ref a) => ValueRef::Array(a),
+            Value::Geometry(srid, ref wkb) => ValueRef::Geometry(srid, wkb),
+        }
+    }
+}
+
+impl ToValue for ValueRef<'_> {
+    fn to_value(&self) -> Value {
+        match *self {
+            ValueRef::Null => Value::Null,
+            ValueRef::Integer(i) => Value::Integer(i),
+            ValueRef::Float(f) => Value::Float(f),
+            ValueRef::Decimal(s) => Value::Decimal(s.to_string()),
+            ValueRef::Text(s) => Value::Text(s.to_string()),
+            ValueRef::Blob(b) => Value::Blob(b.to_vec()),
+            ValueRef::DateTime(y, m, d, h, mi, s, us) => Value::DateTime(y, m, d, h, mi, s, us),
+            ValueRef::Date(y, m, d) => Value::Date(y, m, d),
+            ValueRef::Time(h, mi, s) => Value::Time(h, mi, s),
+            ValueRef::IntervalYearMonth(neg, y, m) => Value::IntervalYearMonth(neg, y, m),
+            ValueRef::IntervalDaySecond(neg, d, h, mi, s, us) => {
+                Value::IntervalDaySecond(neg, d, h, mi, s, us)
+            }
+            ValueRef::DateTimeTz(y, m, d, h, mi, s, us, tz) => {
+                Value::DateTimeTz(y, m, d, h, mi, s, us, tz)
+            }
+            ValueRef::TimeTz(h, mi, s, tz) => Value::TimeTz(h, mi, s, tz),
+            ValueRef::BFile(dir, file) => Value::BFile(dir.to_string(), file.to_string()),
+            ValueRef::RowId(s) => Value::RowId(s.to_string()),
+            ValueRef::Object(o) => Value::Object(o.clone()),
+            ValueRef::Array(a) => Value::Array(a.to_vec()),
+            ValueRef::Geometry(srid, wkb) => Value::Geometry(srid, wkb.to_vec()),
+        }
+    }
+
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(*self)
+    }
+}
+
+/// Conversion borrowing from the row cache, see [`Row::get_ref`](crate::Row::get_ref)
+pub trait FromValueRef<'a>: Sized {
+    fn from_value_ref(v: ValueRef<'a>) -> Result<Self>;
+}
+
+impl<'a> FromValueRef<'a> for ValueRef<'a> {
+    fn from_value_ref(v: ValueRef<'a>) -> Result<Self> {
+        Ok(v)
+    }
+}
+
+impl<'a> FromValueRef<'a> for &'a str {
+    fn from_value_ref(v: ValueRef<'a>) -> Result<Self> {
+        match v {
+            ValueRef::Text(s) => Ok(s),
+            ValueRef::Decimal(s) => Ok(s),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl<'a> FromValueRef<'a> for &'a [u8] {
+    fn from_value_ref(v: ValueRef<'a>) -> Result<Self> {
+        match v {
+            ValueRef::Text(s) => Ok(s.as_bytes()),
+            ValueRef::Blob(b) => Ok(b),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl<'a> FromValueRef<'a> for &'a Object {
+    fn from_value_ref(v: ValueRef<'a>) -> Result<Self> {
+        match v {
+            ValueRef::Object(o) => Ok(o),
+            _ => Err(Error::FromValue(format!(
+                "Value type mismatch, cannot convert `{:?}` to {}",
+                v,
+                type_name::<Self>()
+            ))),
+        }
+    }
+}
+
+impl<'a, T: FromValueRef<'a>> FromValueRef<'a> for Option<T> {
+    fn from_value_ref(v: ValueRef<'a>) -> Result<Self> {
+        if let ValueRef::Null = v {
+            Ok(None)
+        } else {
+            Ok(Some(T::from_value_ref(v)?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_to_integer_boundaries() {
+        // 2^64 and 2^63, the largest floats below them are 2048 and 1024 less
+        let two_64 = 18446744073709551616.0;
+        let two_63 = 9223372036854775808.0;
+
+        assert!(u64::from_value(Value::Float(two_64)).is_err());
+        assert_eq!(
+            u64::from_value(Value::Float(two_64 - 2048.0)).unwrap(),
+            u64::MAX - 2047
+        );
+        assert!(u64::from_value(Value::Float(-1.0)).is_err());
+        assert!(u64::from_value(Value::Integer(-1)).is_err());
+
+        assert!(i64::from_value(Value::Float(two_63)).is_err());
+        assert_eq!(
+            i64::from_value(Value::Float(two_63 - 1024.0)).unwrap(),
+            i64::MAX - 1023
+        );
+        assert_eq!(i64::from_value(Value::Float(-two_63)).unwrap(), i64::MIN);
+        assert!(i64::from_value(Value::Float(-two_63 - 2048.0)).is_err());
+
+        assert_eq!(
+            i32::from_value(Value::Float(2147483647.0)).unwrap(),
+            i32::MAX
+        );
+        assert!(i32::from_value(Value::Float(2147483648.0)).is_err());
+        assert_eq!(
+            i32::from_value(Value::Float(-2147483648.0)).unwrap(),
+            i32::MIN
+        );
+        assert!(i32::from_value(Value::Float(0.5)).is_err());
+        assert!(i32::from_value(Value::Float(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let dates: Vec<DateTuple> = vec![(2021, 3, 1), (2022, 12, 31)];
+        let v = dates.to_value();
+        assert_eq!(
+            v,
+            Value::Array(vec![Value::Date(2021, 3, 1), Value::Date(2022, 12, 31)])
+        );
+        assert_eq!(Vec::<DateTuple>::from_value(v).unwrap(), dates);
+
+        // Binary data is not a collection
+        assert_eq!(vec![1u8, 2].to_value(), Value::Blob(vec![1, 2]));
+        assert_eq!(
+            vec![Some(1u8), None].to_value(),
+            Value::Array(vec![Value::Integer(1), Value::Null])
+        );
+    }
+
+    #[test]
+    fn unsigned_to_value() {
+        assert_eq!(
+            (i64::MAX as u64).try_to_value().unwrap(),
+            Value::Integer(i64::MAX)
+        );
+        assert!(matches!(
+            (i64::MAX as u64 + 1).try_to_value(),
+            Err(Error::Parameter(_))
+        ));
+        assert_eq!(
+            u64::MAX.to_value(),
+            Value::Decimal("18446744073709551615".into())
+        );
+        assert!(vec![1u64, u64::MAX].try_to_value().is_err());
+    }
+
+    #[test]
+    fn integer_to_float() {
+        assert_eq!(
+            f64::from_value(Value::Integer(1 << 53)).unwrap(),
+            9007199254740992.0
+        );
+        assert!(f64::from_value(Value::Integer((1 << 53) + 1)).is_err());
+        assert!(f64::from_value(Value::Integer(i64::MAX)).is_err());
+        assert_eq!(
+            f64::from_value(Value::Integer(i64::MIN)).unwrap(),
+            -9223372036854775808.0
+        );
+        assert_eq!(
+            f32::from_value(Value::Integer(1 << 24)).unwrap(),
+            16777216.0
+        );
+        assert!(f32::from_value(Value::Integer((1 << 24) + 1)).is_err());
+        assert_eq!(
+            Lossy::<f32>::from_value(Value::Integer((1 << 24) + 1)).unwrap(),
+            Lossy(16777216.0)
+        );
+    }
+
+    #[test]
+    fn lossy_decimal() {
+        // Beyond the 53 bits of f64, parsing as a float would round the value
+        let s = "9007199254740993.9";
+        assert_eq!(
+            Lossy::<i64>::from_value(Value::Decimal(s.into())).unwrap(),
+            Lossy(9007199254740993)
+        );
+        assert_eq!(
+            Lossy::<i32>::from_value(Value::Decimal("-3.9".into())).unwrap(),
+            Lossy(-3)
+        );
+        assert_eq!(
+            Lossy::<i32>::from_value(Value::Decimal("-.5".into())).unwrap(),
+            Lossy(0)
+        );
+        // Wrapped like an integer
+        assert_eq!(
+            Lossy::<u8>::from_value(Value::Decimal("257.5".into())).unwrap(),
+            Lossy(1)
+        );
+        // Saturated like a float beyond `i128`
+        assert_eq!(
+            Lossy::<i64>::from_value(Value::Decimal("1e40".into())).unwrap(),
+            Lossy(i64::MAX)
+        );
+        assert_eq!(
+            Lossy::<f64>::from_value(Value::Decimal("2.5".into())).unwrap(),
+            Lossy(2.5)
+        );
+        assert_eq!(
+            Lossy::<i32>::from_value(Value::Decimal("1.5E+3".into())).unwrap(),
+            Lossy(1500)
+        );
+        assert!(Lossy::<i32>::from_value(Value::Decimal("abc".into())).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use dmdb::{params, Connection};
+use dmdb::{params, Connection, RowId, SqlType, Value};
 
 const INIT_SQL: &'static str = r#"
 DROP TABLE IF EXISTS dmdb_test;
@@ -27,7 +27,11 @@ CREATE TABLE dmdb_test (
     s TEXT,
     t CLOB,
     u DATETIME,
-    v BLOB
+    v BLOB,
+    w DATE,
+    x TIME,
+    y INTERVAL YEAR TO MONTH,
+    z INTERVAL DAY TO SECOND
 );
 "#;
 
@@ -57,6 +61,10 @@ struct Test {
     t: String,
     u: (u16, u8, u8, u8, u8, u8, u32),
     v: Vec<u8>,
+    w: (u16, u8, u8),
+    x: (u8, u8, u8),
+    y: (bool, u32, u32),
+    z: std::time::Duration,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -67,6 +75,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .collect::<Vec<_>>()
         .join(",");
     let blob_data: Vec<u8> = (0..8000).map(|v| v as u8).collect();
+    let interval = std::time::Duration::from_micros(90_061_000_001);
 
     // Init
     for sql in INIT_SQL.split(";") {
@@ -77,11 +86,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Insert
     let mut stmt = conn.prepare(
-        "INSERT INTO dmdb_test (nil, a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r, s, t, u, v) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO dmdb_test (nil, a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r, s, t, u, v, w, x, y, z) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
     )?;
+    assert_eq!(stmt.param_count()?, 27);
+    let param_infos = stmt.params()?;
+    assert_eq!(param_infos[0].sql_type(), SqlType::Int);
+    assert_eq!(param_infos[11].sql_type(), SqlType::Varchar);
+    assert_eq!(param_infos[11].precision(), Some(10));
     #[rustfmt::skip]
     stmt.execute(params![
-        None::<u32>, 1, 2, 3, 4, 5, 6, 7, 8.1, true, "jj", "kkk中文", "ll", "m", 13.1, 14.1, 15.1, 16.1, 17.1, text_data, "t", (2021u16, 3u8, 1u8, 15u8, 38u8, 0u8, 123456u32), blob_data,
+        None::<u32>, 1, 2, 3, 4, 5, 6, 7, 8.1, true, "jj", "kkk中文", "ll", "m", 13.1, 14.1, 15.1, 16.1, 17.1, text_data, "t", (2021u16, 3u8, 1u8, 15u8, 38u8, 0u8, 123456u32), blob_data, (2022u16, 4u8, 2u8), (16u8, 39u8, 1u8), (true, 1u32, 2u32), interval,
     ])?;
     drop(stmt);
     let id = conn.ident_current(&"dmdb_test".to_uppercase())?;
@@ -91,7 +105,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Get
     let tuple = conn.query_row(
-        "SELECT id, nil, a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r, s, t, u, v FROM dmdb_test",
+        "SELECT id, nil, a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r, s, t, u, v, w, x, y, z FROM dmdb_test",
         [],
         |row| {
             println!("cols: {:?}", row.columns());
@@ -121,6 +135,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 t: row.get(22)?,
                 u: row.get(23)?,
                 v: row.get(24)?,
+                w: row.get(25)?,
+                x: row.get(26)?,
+                y: row.get(27)?,
+                z: row.get(28)?,
             })
         },
     )?;
@@ -152,9 +170,78 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             s: text_data,
             t: "t".into(),
             u: (2021, 3, 1, 15, 38, 0, 123456),
-            v: blob_data
+            v: blob_data,
+            w: (2022, 4, 2),
+            x: (16, 39, 1),
+            y: (true, 1, 2),
+            z: interval,
         }
     );
 
+    // Get borrowed
+    conn.query_row("SELECT k, s, v FROM dmdb_test", [], |row| {
+        let k: &str = row.get_ref(1)?;
+        assert_eq!(k, "kkk中文");
+        let v: Option<&[u8]> = row.get_ref(3)?;
+        assert_eq!(v, Some(&tuple.v[..]));
+        Ok(())
+    })?;
+    conn.query_row("SELECT s FROM dmdb_test", [], |mut row| {
+        let s = row.take(1)?;
+        assert_eq!(s, Value::Text(tuple.s.clone()));
+        assert_eq!(row.get_value(1)?, Value::Null);
+        Ok(())
+    })?;
+
+    // Re-execute without the parameters, the borrowed ones of the last execution are unbound
+    let mut stmt = conn.prepare("SELECT COUNT(*) FROM dmdb_test WHERE k = ?")?;
+    let k = String::from("kkk中文");
+    stmt.execute(params![k.as_str()])?;
+    drop(k);
+    assert!(stmt.execute([]).is_err());
+    drop(stmt);
+
+    // Column info
+    let stmt = conn.prepare("SELECT k AS kk, n FROM dmdb_test")?;
+    let cols = stmt.columns()?;
+    assert_eq!(cols.len(), 2);
+    assert_eq!(cols[1].sql_type(), SqlType::Decimal);
+    drop(stmt);
+    conn.query_row("SELECT k AS kk, n FROM dmdb_test", [], |row| {
+        let cols = row.columns();
+        assert_eq!(cols[0].sql_type(), SqlType::Varchar);
+        assert_eq!(cols[0].label(), "KK");
+        assert_eq!(cols[0].table(), "DMDB_TEST");
+        assert_eq!(cols[1].sql_type(), SqlType::Decimal);
+        assert_eq!((cols[1].precision(), cols[1].scale()), (Some(10), 2));
+        assert_eq!(cols[1].nullable(), Some(true));
+        Ok(())
+    })?;
+
+    // Update by rowid
+    let rowid: RowId = conn.query_row("SELECT ROWID FROM dmdb_test", [], |row| row.get(1))?;
+    conn.execute(
+        "UPDATE dmdb_test SET a = ? WHERE ROWID = ?",
+        params![100, rowid],
+    )?;
+    let a: i32 = conn.query_row("SELECT a FROM dmdb_test", [], |row| row.get(1))?;
+    assert_eq!(a, 100);
+
+    // Bind values of other types than the columns, they are converted by the server
+    conn.execute(
+        "UPDATE dmdb_test SET a = ?, k = ?, n = ?, x = ?",
+        params![
+            "42",
+            42,
+            Value::Decimal("-0.5".into()),
+            (2021u16, 3u8, 1u8, 15u8, 38u8, 0u8, 0u32),
+        ],
+    )?;
+    let (a, k, n, x): (i32, String, f64, (u8, u8, u8)) =
+        conn.query_row("SELECT a, k, n, x FROM dmdb_test", [], |row| {
+            Ok((row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+        })?;
+    assert_eq!((a, k.as_str(), n, x), (42, "42", -0.5, (15, 38, 0)));
+
     Ok(())
 }
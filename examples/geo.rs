@@ -0,0 +1,61 @@
+use dmdb::{params, Connection, Srid};
+use geo_types::{line_string, point, polygon, Geometry, Point, Polygon};
+
+const INIT_SQL: &[&str] = &[
+    "DROP TABLE IF EXISTS dmdb_test",
+    r#"
+CREATE TABLE dmdb_test (
+    id INTEGER PRIMARY KEY IDENTITY(1,1),
+    g SYSGEO.ST_GEOMETRY,
+    p SYSGEO.ST_POINT,
+    a SYSGEO.ST_POLYGON
+)
+"#,
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = Connection::connect("127.0.0.1:5236", "SYSDBA", "SYSDBA001")?;
+
+    // Init
+    for sql in INIT_SQL {
+        conn.execute(sql, [])?;
+    }
+
+    // Insert, the subtype columns are bound by their own types
+    let point = point!(x: 116.4, y: 39.9);
+    let line = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0), (x: 2.0, y: 0.0)];
+    let area = polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 0.0)];
+    conn.execute(
+        "INSERT INTO dmdb_test (g, p, a) VALUES (?, ?, ?)",
+        params![line.clone(), Srid(4326, point), area.clone()],
+    )?;
+    conn.execute("INSERT INTO dmdb_test (g) VALUES (NULL)", [])?;
+
+    // Get
+    let mut stmt = conn.prepare("SELECT g, p, a FROM dmdb_test ORDER BY id")?;
+    let mut rows = stmt.query([])?;
+
+    let row = rows.next()?.unwrap();
+    let g: Geometry<f64> = row.get(1)?;
+    assert_eq!(g, Geometry::LineString(line));
+    let p: Srid<Point<f64>> = row.get(2)?;
+    assert_eq!(p, Srid(4326, point));
+    let a: Polygon<f64> = row.get(3)?;
+    assert_eq!(a, area);
+
+    let row = rows.next()?.unwrap();
+    let g: Option<Geometry<f64>> = row.get(1)?;
+    assert_eq!(g, None);
+    drop(rows);
+    drop(stmt);
+
+    // WKB of the server functions
+    let p: Point<f64> = conn.query_row(
+        "SELECT DMGEO.ST_AsBinary(p) FROM dmdb_test WHERE id = 1",
+        [],
+        |row| row.get(1),
+    )?;
+    assert_eq!(p, point);
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use dmdb::{params, Connection, LobStream};
+
+const INIT_SQL: &str = r#"
+DROP TABLE IF EXISTS dmdb_test;
+
+CREATE TABLE dmdb_test (
+    id INTEGER PRIMARY KEY IDENTITY(1,1),
+    a BLOB,
+    b CLOB
+);
+"#;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = Connection::connect("127.0.0.1:5236", "SYSDBA", "SYSDBA001")?;
+
+    let blob_data: Vec<u8> = (0..100000).map(|v| v as u8).collect();
+    let text_data = (0..10000)
+        .map(|i| format!("{i}中文"))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    // Init
+    for sql in INIT_SQL.split(";") {
+        if !sql.trim().is_empty() {
+            conn.execute(sql, [])?;
+        }
+    }
+
+    // Insert
+    conn.execute(
+        "INSERT INTO dmdb_test (a, b) VALUES (?, ?)",
+        params![blob_data, text_data],
+    )?;
+    conn.execute("INSERT INTO dmdb_test (a, b) VALUES (NULL, NULL)", [])?;
+    conn.execute(
+        "INSERT INTO dmdb_test (a, b) VALUES (?, ?)",
+        params![
+            LobStream::blob(blob_data.as_slice()),
+            LobStream::clob(text_data.as_bytes())
+        ],
+    )?;
+
+    // Stream read
+    let mut stmt = conn.prepare("SELECT a, b FROM dmdb_test ORDER BY id")?;
+    let mut rows = stmt.query([])?;
+    rows.stream_lob(1)?;
+    rows.stream_lob(2)?;
+
+    let row = rows.next()?.ok_or("no rows")?;
+    let mut blob = row.get_lob(1)?.ok_or("blob is null")?;
+    assert_eq!(blob.len(), blob_data.len() as u64);
+    let mut buf = vec![];
+    blob.read_to_end(&mut buf)?;
+    assert_eq!(buf, blob_data);
+
+    blob.seek(SeekFrom::Start(1000))?;
+    let mut buf = [0u8; 10];
+    blob.read_exact(&mut buf)?;
+    assert_eq!(&buf[..], &blob_data[1000..1010]);
+
+    let mut clob = row.get_lob(2)?.ok_or("clob is null")?;
+    let mut text = String::new();
+    clob.read_to_string(&mut text)?;
+    assert_eq!(text, text_data);
+
+    // Small reads split the multibyte chars across the chunks
+    let mut clob = row.get_lob(2)?.ok_or("clob is null")?;
+    let mut bytes = vec![];
+    let mut buf = [0u8; 7];
+    loop {
+        let n = clob.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&buf[..n]);
+    }
+    assert_eq!(String::from_utf8(bytes)?, text_data);
+    drop(row);
+
+    let row = rows.next()?.ok_or("no rows")?;
+    assert!(row.get_lob(1)?.is_none());
+    assert!(row.get_lob(2)?.is_none());
+    drop(row);
+
+    // Check streamed insert
+    let row = rows.next()?.ok_or("no rows")?;
+    let mut buf = vec![];
+    row.get_lob(1)?
+        .ok_or("blob is null")?
+        .read_to_end(&mut buf)?;
+    assert_eq!(buf, blob_data);
+    let mut text = String::new();
+    row.get_lob(2)?
+        .ok_or("clob is null")?
+        .read_to_string(&mut text)?;
+    assert_eq!(text, text_data);
+    drop(row);
+    drop(rows);
+    drop(stmt);
+
+    // Edit in place
+    {
+        let tx = conn.transaction()?;
+        let mut stmt = tx.prepare("SELECT a, b FROM dmdb_test WHERE id = 1 FOR UPDATE")?;
+        let mut rows = stmt.query([])?;
+        rows.stream_lob(1)?;
+        rows.stream_lob(2)?;
+        let row = rows.next()?.ok_or("no rows")?;
+
+        let blob = row.get_lob_locator(1)?.ok_or("blob is null")?;
+        assert_eq!(blob.read(10, 5)?, &blob_data[10..15]);
+        assert_eq!(blob.truncate(100)?, 100);
+        blob.append(&[1, 2, 3])?;
+        assert_eq!(blob.len()?, 103);
+
+        let clob = row.get_lob_locator(2)?.ok_or("clob is null")?;
+        clob.truncate(0)?;
+        clob.append("日志".as_bytes())?;
+        assert_eq!(clob.read(0, 2)?, "日志".as_bytes());
+
+        // Copy the locator on the server side
+        tx.execute("UPDATE dmdb_test SET a = ? WHERE id = 2", params![blob])?;
+        drop(row);
+        drop(rows);
+        drop(stmt);
+        tx.commit()?;
+    }
+    let blob: Vec<u8> =
+        conn.query_row("SELECT a FROM dmdb_test WHERE id = 2", [], |row| row.get(1))?;
+    assert_eq!(blob.len(), 103);
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use dmdb::{params, Connection, Object};
+
+const INIT_SQL: &[&str] = &[
+    "DROP TABLE IF EXISTS dmdb_test",
+    r#"
+CREATE OR REPLACE CLASS dmdb_point AS
+    x INT;
+    y INT;
+    label VARCHAR(20);
+END
+"#,
+    "CREATE OR REPLACE TYPE dmdb_ids AS VARRAY(100) OF INT",
+    r#"
+CREATE TABLE dmdb_test (
+    id INTEGER PRIMARY KEY IDENTITY(1,1),
+    p dmdb_point,
+    ids dmdb_ids
+)
+"#,
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = Connection::connect("127.0.0.1:5236", "SYSDBA", "SYSDBA001")?;
+
+    // Init
+    for sql in INIT_SQL {
+        conn.execute(sql, [])?;
+    }
+
+    // Insert
+    let point = Object::new("DMDB_POINT")
+        .attribute("x", 1)
+        .attribute("y", 2)
+        .attribute("label", "a");
+    conn.execute(
+        "INSERT INTO dmdb_test (p, ids) VALUES (?, ?)",
+        params![point, vec![1, 2, 3]],
+    )?;
+    conn.execute("INSERT INTO dmdb_test (p, ids) VALUES (NULL, NULL)", [])?;
+
+    // Get
+    let mut stmt = conn.prepare("SELECT p, ids FROM dmdb_test ORDER BY id")?;
+    let mut rows = stmt.query([])?;
+
+    let row = rows.next()?.unwrap();
+    let p: Object = row.get(1)?;
+    assert_eq!(p.name, "DMDB_POINT");
+    assert_eq!(p.get::<i32>("x")?, 1);
+    assert_eq!(p.get::<i32>("y")?, 2);
+    assert_eq!(p.get::<String>("label")?, "a");
+    let ids: Vec<i32> = row.get(2)?;
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    let row = rows.next()?.unwrap();
+    let p: Option<Object> = row.get(1)?;
+    assert_eq!(p, None);
+    let ids: Option<Vec<i32>> = row.get(2)?;
+    assert_eq!(ids, None);
+
+    Ok(())
+}
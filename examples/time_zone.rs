@@ -0,0 +1,54 @@
+use dmdb::{params, Connection, DateTimeTuple, DateTimeTzTuple, TimeTzTuple};
+
+const INIT_SQL: &str = r#"
+DROP TABLE IF EXISTS dmdb_tz;
+
+CREATE TABLE dmdb_tz (
+    a TIMESTAMP WITH TIME ZONE,
+    b TIME WITH TIME ZONE,
+    c TIMESTAMP WITH LOCAL TIME ZONE
+);
+"#;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = Connection::connect("127.0.0.1:5236", "SYSDBA", "SYSDBA001")?;
+
+    // Init
+    for sql in INIT_SQL.split(";") {
+        if !sql.trim().is_empty() {
+            conn.execute(sql, [])?;
+        }
+    }
+
+    // Round trip with positive and negative offsets
+    let ts = (2021u16, 3u8, 1u8, 15u8, 38u8, 0u8, 123456u32, 480i16);
+    let time = (16u8, 39u8, 1u8, -330i16);
+    conn.execute(
+        "INSERT INTO dmdb_tz (a, b) VALUES (?, ?)",
+        params![ts, time],
+    )?;
+    let (a, b): (DateTimeTzTuple, TimeTzTuple) =
+        conn.query_row("SELECT a, b FROM dmdb_tz", [], |row| {
+            Ok((row.get(1)?, row.get(2)?))
+        })?;
+    assert_eq!(a, ts);
+    assert_eq!(b, time);
+
+    // Local time zone values are stored in UTC and read in the session time zone
+    conn.set_time_zone(480)?;
+    let local = (2021u16, 3u8, 1u8, 15u8, 38u8, 0u8, 123456u32);
+    conn.execute("UPDATE dmdb_tz SET c = ?", params![local])?;
+    conn.set_time_zone(-60)?;
+    let c: DateTimeTuple = conn.query_row("SELECT c FROM dmdb_tz", [], |row| row.get(1))?;
+    assert_eq!(c, (2021, 3, 1, 6, 38, 0, 123456));
+
+    // Session time zone
+    conn.set_time_zone(-300)?;
+    assert_eq!(conn.time_zone()?, -300);
+
+    // Out of range offset is rejected and the previous one is kept
+    assert!(conn.set_time_zone(10000).is_err());
+    assert_eq!(conn.time_zone()?, -300);
+
+    Ok(())
+}